@@ -0,0 +1,62 @@
+use super::NodeID;
+use std::collections::HashMap;
+
+// 连通分量：为每个节点标记所属分量的编号
+#[derive(Debug)]
+pub struct Components {
+    id_of: HashMap<NodeID, usize>,
+    count: usize,
+}
+
+impl Components {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            id_of: HashMap::with_capacity(capacity),
+            count: 0,
+        }
+    }
+
+    // 新开一个分量，返回其编号
+    pub(crate) fn open(&mut self, id: NodeID) -> usize {
+        self.id_of.insert(id, self.count);
+        self.count += 1;
+        self.count - 1
+    }
+
+    pub(crate) fn join(&mut self, id: NodeID, component: usize) {
+        self.id_of.insert(id, component);
+    }
+}
+
+impl Components {
+    pub fn id(&self, node: NodeID) -> Option<usize> {
+        self.id_of.get(&node).copied()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn connected(&self, id1: NodeID, id2: NodeID) -> bool {
+        matches!((self.id(id1), self.id(id2)), (Some(c1), Some(c2)) if c1 == c2)
+    }
+
+    pub fn labels(&self) -> &HashMap<NodeID, usize> {
+        &self.id_of
+    }
+
+    // 按分量编号分组，组内节点升序
+    pub fn members(&self) -> Vec<Vec<NodeID>> {
+        let mut members = vec![Vec::new(); self.count];
+
+        for (&id, &component) in &self.id_of {
+            members[component].push(id);
+        }
+
+        for group in &mut members {
+            group.sort_unstable();
+        }
+
+        members
+    }
+}
@@ -0,0 +1,185 @@
+use super::{GraphError, NodeID, WeiEdge, Weight};
+use std::ops::Range;
+
+mod mst;
+mod search;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::search::CsrPaths;
+
+// 压缩稀疏行存储的只读图：节点编号按升序映射为稠密下标 0..n，
+// 下标 i 的邻接弧位于 offsets[i]..offsets[i + 1]
+#[derive(Debug)]
+pub struct CsrGraph<W = i32> {
+    directed: bool,
+    ids: Vec<NodeID>,
+    offsets: Vec<usize>,
+    // 以下三者按弧一一对应：终点下标，权重，所属边在 edges 中的下标
+    targets: Vec<usize>,
+    weights: Vec<W>,
+    edge_of: Vec<usize>,
+    edges: Vec<WeiEdge<W>>,
+}
+
+impl<W: Weight> CsrGraph<W> {
+    // 无向边在两个端点处各存一条弧，自环只存一条
+    pub(crate) fn build(
+        directed: bool,
+        ids: impl IntoIterator<Item = NodeID>,
+        mut edges: Vec<WeiEdge<W>>,
+    ) -> Self {
+        let mut ids: Vec<_> = ids.into_iter().collect();
+        ids.sort_unstable();
+        edges.sort_unstable_by_key(|edge| edge.id);
+
+        let index = |id| ids.binary_search(&id).unwrap();
+        let arcs = |edge: &WeiEdge<W>| {
+            let (v, w) = edge.endpoints();
+            let reverse = (!directed && v != w).then(|| (index(w), index(v)));
+
+            [(index(v), index(w))].into_iter().chain(reverse)
+        };
+
+        // 先统计各节点的弧数，前缀和即各节点弧段的起点
+        let mut offsets = vec![0; ids.len() + 1];
+
+        for (from, _) in edges.iter().flat_map(arcs) {
+            offsets[from + 1] += 1;
+        }
+
+        for i in 0..ids.len() {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let arc_count = offsets[ids.len()];
+        let mut next = offsets.clone();
+        let mut targets = vec![0; arc_count];
+        let mut weights = vec![W::zero(); arc_count];
+        let mut edge_of = vec![0; arc_count];
+
+        for (i, edge) in edges.iter().enumerate() {
+            for (from, to) in arcs(edge) {
+                let arc = next[from];
+                next[from] += 1;
+
+                targets[arc] = to;
+                weights[arc] = edge.weight;
+                edge_of[arc] = i;
+            }
+        }
+
+        Self {
+            directed,
+            ids,
+            offsets,
+            targets,
+            weights,
+            edge_of,
+            edges,
+        }
+    }
+
+    fn arcs(&self, index: usize) -> Range<usize> {
+        self.offsets[index]..self.offsets[index + 1]
+    }
+
+    fn index(&self, id: NodeID) -> Result<usize, GraphError> {
+        self.index_of(id).ok_or(GraphError::NodeNotInGraph(id))
+    }
+}
+
+impl<W: Weight> CsrGraph<W> {
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    // 节点编号，升序，位置即稠密下标
+    pub fn ids(&self) -> &[NodeID] {
+        &self.ids
+    }
+
+    pub fn contains_id(&self, id: NodeID) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    pub fn index_of(&self, id: NodeID) -> Option<usize> {
+        self.ids.binary_search(&id).ok()
+    }
+
+    pub fn id_of(&self, index: usize) -> Option<NodeID> {
+        self.ids.get(index).copied()
+    }
+
+    // 按边的编号升序
+    pub fn edges(&self) -> &[WeiEdge<W>] {
+        &self.edges
+    }
+
+    // 有向图为出边，无向图为所有邻接边，附带另一端点
+    pub fn neighbours(
+        &self,
+        id: NodeID,
+    ) -> Result<impl Iterator<Item = (NodeID, &WeiEdge<W>)> + '_, GraphError> {
+        let arcs = self.arcs(self.index(id)?);
+
+        Ok(arcs.map(|arc| (self.ids[self.targets[arc]], &self.edges[self.edge_of[arc]])))
+    }
+
+    pub fn degree(&self, id: NodeID) -> Result<usize, GraphError> {
+        self.index(id).map(|index| self.arcs(index).len())
+    }
+}
+
+// 最短路径方法
+impl<W: Weight> CsrGraph<W> {
+    // 按边数计的最短路径
+    pub fn bfs(&self, src: NodeID) -> Result<CsrPaths<'_, usize>, GraphError> {
+        self.index(src).map(|src| search::bfs(self, src))
+    }
+
+    // 要求所有边权非负
+    pub fn dijkstra(&self, src: NodeID) -> Result<CsrPaths<'_, W>, GraphError> {
+        self.index(src).and_then(|src| search::dijkstra(self, src))
+    }
+}
+
+// 生成树方法，只适用于无向图
+impl<W: Weight> CsrGraph<W> {
+    fn spanned<'a>(&self, mst: Vec<&'a WeiEdge<W>>) -> Result<Vec<&'a WeiEdge<W>>, GraphError> {
+        (mst.len() + 1 == self.node_count())
+            .then_some(mst)
+            .ok_or(GraphError::Disconnected)
+    }
+
+    fn undirected(&self) -> Result<(), GraphError> {
+        match (self.directed, self.is_empty()) {
+            (true, _) => Err(GraphError::Directed),
+            (_, true) => Err(GraphError::EmptyGraph),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn kruskal_mst(&self) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        self.undirected()?;
+        self.spanned(mst::kruskal(self))
+    }
+
+    pub fn prim_mst(&self, root: NodeID) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        self.undirected()?;
+        self.spanned(mst::prim(self, self.index(root)?))
+    }
+}
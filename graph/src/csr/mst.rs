@@ -0,0 +1,63 @@
+use super::CsrGraph;
+use crate::{WeiEdge, Weight};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::iter;
+use union_find::UF;
+
+// 图不连通时，得到的是最小生成森林
+pub(super) fn kruskal<W: Weight>(graph: &CsrGraph<W>) -> Vec<&WeiEdge<W>> {
+    let mut uf = UF::from_iter(graph.ids().iter().copied());
+    let branches = graph.node_count().saturating_sub(1);
+    let mut mst = Vec::with_capacity(branches);
+
+    let mut edges: Vec<_> = graph.edges.iter().collect();
+    edges.sort_unstable_by(|a, b| a.cmp_weight(b));
+
+    for edge in edges {
+        if mst.len() == branches {
+            break;
+        }
+
+        let (v, w) = edge.endpoints();
+
+        if !uf.connected(v, w) {
+            uf.union(v, w);
+            mst.push(edge);
+        }
+    }
+
+    mst
+}
+
+// 延时实现：队列中以（权重，弧）为键，出队时跳过终点已在树中的弧
+pub(super) fn prim<W: Weight>(graph: &CsrGraph<W>, root: usize) -> Vec<&WeiEdge<W>> {
+    let mut marked = vec![false; graph.node_count()];
+    let mut pq = BinaryHeap::new();
+    let mut mst = Vec::with_capacity(graph.node_count().saturating_sub(1));
+    let mut v = root;
+
+    marked[root] = true;
+
+    loop {
+        for arc in graph.arcs(v) {
+            if !marked[graph.targets[arc]] {
+                pq.push(Reverse((graph.weights[arc], arc)));
+            }
+        }
+
+        // 取出通往树外的最小弧
+        let Some(arc) = iter::from_fn(|| pq.pop())
+            .map(|Reverse((_, arc))| arc)
+            .find(|&arc| !marked[graph.targets[arc]])
+        else {
+            break;
+        };
+
+        v = graph.targets[arc];
+        marked[v] = true;
+        mst.push(&graph.edges[graph.edge_of[arc]]);
+    }
+
+    mst
+}
@@ -0,0 +1,123 @@
+use super::CsrGraph;
+use crate::{GraphError, NodeID, Weight};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+// 单源最短路径，按稠密下标存储
+#[derive(Debug)]
+pub struct CsrPaths<'a, W = i32> {
+    ids: &'a [NodeID],
+    src: usize,
+    dist_to: Vec<Option<W>>,
+    // 最短路径树中前驱的下标
+    edge_to: Vec<Option<usize>>,
+}
+
+impl<'a, W: Weight> CsrPaths<'a, W> {
+    fn new(ids: &'a [NodeID], src: usize) -> Self {
+        let mut dist_to = vec![None; ids.len()];
+        dist_to[src] = Some(W::zero());
+
+        Self {
+            ids,
+            src,
+            dist_to,
+            edge_to: vec![None; ids.len()],
+        }
+    }
+
+    fn index(&self, id: NodeID) -> Option<usize> {
+        self.ids.binary_search(&id).ok()
+    }
+}
+
+impl<W: Weight> CsrPaths<'_, W> {
+    pub fn src(&self) -> NodeID {
+        self.ids[self.src]
+    }
+
+    pub fn dist_to(&self, id: NodeID) -> Option<W> {
+        self.index(id).and_then(|index| self.dist_to[index])
+    }
+
+    pub fn has_path_to(&self, id: NodeID) -> bool {
+        self.dist_to(id).is_some()
+    }
+
+    pub fn edge_to(&self, id: NodeID) -> Option<NodeID> {
+        self.index(id)
+            .and_then(|index| self.edge_to[index])
+            .map(|prev| self.ids[prev])
+    }
+
+    pub fn path_to(&self, dest: NodeID) -> Option<VecDeque<NodeID>> {
+        let mut index = self.index(dest)?;
+        self.dist_to[index]?;
+
+        let mut path = VecDeque::from([dest]);
+
+        // 沿最短路径树回溯至起点
+        while let Some(prev) = self.edge_to[index] {
+            path.push_front(self.ids[prev]);
+            index = prev;
+        }
+
+        Some(path)
+    }
+}
+
+pub(super) fn bfs<W: Weight>(graph: &CsrGraph<W>, src: usize) -> CsrPaths<'_, usize> {
+    let mut paths = CsrPaths::new(&graph.ids, src);
+    let mut queue = VecDeque::from([src]);
+
+    while let Some(v) = queue.pop_front() {
+        let dist = paths.dist_to[v].unwrap() + 1;
+
+        for arc in graph.arcs(v) {
+            let w = graph.targets[arc];
+
+            if paths.dist_to[w].is_none() {
+                paths.dist_to[w] = Some(dist);
+                paths.edge_to[w] = Some(v);
+                queue.push_back(w);
+            }
+        }
+    }
+
+    paths
+}
+
+// 遇到负权边即返回错误
+pub(super) fn dijkstra<W: Weight>(
+    graph: &CsrGraph<W>,
+    src: usize,
+) -> Result<CsrPaths<'_, W>, GraphError> {
+    let mut paths = CsrPaths::new(&graph.ids, src);
+    let mut pq = BinaryHeap::from([Reverse((W::zero(), src))]);
+
+    // 不断取出距起点最近的节点
+    while let Some(Reverse((dist, v))) = pq.pop() {
+        // 队列中的旧距离已失效，跳过
+        if paths.dist_to[v].is_some_and(|best| dist > best) {
+            continue;
+        }
+
+        for arc in graph.arcs(v) {
+            let w = graph.targets[arc];
+
+            if graph.weights[arc].is_negative() {
+                return Err(GraphError::NegativeWeight(graph.ids[v], graph.ids[w]));
+            }
+
+            let next = dist + graph.weights[arc];
+
+            if paths.dist_to[w].is_none_or(|old| next < old) {
+                paths.dist_to[w] = Some(next);
+                paths.edge_to[w] = Some(v);
+                pq.push(Reverse((next, w)));
+            }
+        }
+    }
+
+    Ok(paths)
+}
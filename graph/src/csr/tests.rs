@@ -0,0 +1,119 @@
+use crate::ungraph::tests::sample;
+use crate::{DiGraph, GraphError, UnGraph, WeiDiGraph, WeiEdge};
+use std::collections::VecDeque;
+
+#[test]
+fn layout() {
+    let mut graph = UnGraph::new();
+    graph.add_edge(4, (900, 30), (), ());
+    graph.add_edge(2, (30, 30), (), ());
+    graph.add_edge(1, (30, 900), (), ());
+    graph.add_node(7, ());
+
+    let csr = graph.to_csr();
+
+    assert!(!csr.is_directed());
+    assert_eq!(csr.ids(), [7, 30, 900]);
+    assert_eq!(csr.index_of(900), Some(2));
+    assert_eq!(csr.id_of(1), Some(30));
+    assert_eq!(csr.id_of(3), None);
+    assert_eq!(csr.edge_count(), 3);
+    assert_eq!(
+        csr.edges().iter().map(|edge| edge.id).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+
+    // 自环只占一条弧，平行边各占一条
+    assert_eq!(csr.degree(30), Ok(3));
+    assert_eq!(csr.degree(900), Ok(2));
+    assert_eq!(csr.degree(7), Ok(0));
+    assert_eq!(csr.degree(8), Err(GraphError::NodeNotInGraph(8)));
+
+    let mut neighbours: Vec<_> = csr
+        .neighbours(900)
+        .unwrap()
+        .map(|(id, edge)| (id, edge.weight))
+        .collect();
+    neighbours.sort_unstable();
+    assert_eq!(neighbours, [(30, 1), (30, 4)]);
+}
+
+#[test]
+fn matches_ungraph() {
+    let graph = sample();
+    let csr = graph.to_csr();
+
+    let paths = graph.dijkstra(0).unwrap();
+    let csr_paths = csr.dijkstra(0).unwrap();
+
+    for id in graph.ids() {
+        assert_eq!(csr_paths.dist_to(id), paths.dist_to(id));
+    }
+
+    assert_eq!(csr_paths.src(), 0);
+    assert_eq!(csr_paths.path_to(5), Some(VecDeque::from([0, 7, 5])));
+    assert_eq!(csr_paths.path_to(0), Some(VecDeque::from([0])));
+    assert_eq!(csr_paths.edge_to(5), Some(7));
+    assert_eq!(csr_paths.dist_to(8), None);
+
+    let total = |mst: Vec<&WeiEdge>| mst.iter().map(|edge| edge.weight).sum::<i32>();
+    let expected = total(graph.kruskal_mst().unwrap());
+
+    assert_eq!(total(csr.kruskal_mst().unwrap()), expected);
+    assert_eq!(total(csr.prim_mst(6).unwrap()), expected);
+    assert_eq!(csr.prim_mst(9), Err(GraphError::NodeNotInGraph(9)));
+}
+
+#[test]
+fn digraph_bfs() {
+    let mut graph = DiGraph::new();
+
+    for edge in [(0, 1), (1, 2), (2, 3), (0, 4), (4, 3), (3, 5), (6, 0)] {
+        graph.add_edge(edge, (), ());
+    }
+
+    let csr = graph.to_csr();
+    let paths = csr.bfs(0).unwrap();
+
+    assert!(csr.is_directed());
+    assert_eq!(csr.edge_count(), 7);
+    assert_eq!(paths.dist_to(5), Some(3));
+    assert_eq!(paths.path_to(5), Some(VecDeque::from([0, 4, 3, 5])));
+    assert!(!paths.has_path_to(6));
+    assert_eq!(csr.bfs(7).err(), Some(GraphError::NodeNotInGraph(7)));
+    assert_eq!(csr.kruskal_mst(), Err(GraphError::Directed));
+}
+
+#[test]
+fn wei_digraph_dijkstra() {
+    let mut graph = WeiDiGraph::new();
+
+    graph.add_edge(7, (0, 1), (), ());
+    graph.add_edge(2, (0, 2), (), ());
+    graph.add_edge(3, (2, 1), (), ());
+    graph.add_edge(1, (1, 3), (), ());
+    graph.add_edge(9, (3, 0), (), ());
+
+    let csr = graph.to_csr();
+    let paths = csr.dijkstra(0).unwrap();
+
+    assert_eq!(paths.dist_to(3), Some(6));
+    assert_eq!(paths.path_to(3), Some(VecDeque::from([0, 2, 1, 3])));
+    assert_eq!(csr.dijkstra(3).unwrap().dist_to(2), Some(11));
+}
+
+#[test]
+fn spanning_errors() {
+    assert_eq!(
+        UnGraph::<()>::new().to_csr().kruskal_mst(),
+        Err(GraphError::EmptyGraph)
+    );
+
+    let mut graph = sample();
+    graph.add_node(8, ());
+    let csr = graph.to_csr();
+
+    assert_eq!(csr.kruskal_mst(), Err(GraphError::Disconnected));
+    assert_eq!(csr.prim_mst(0), Err(GraphError::Disconnected));
+    assert_eq!(csr.prim_mst(8), Err(GraphError::Disconnected));
+}
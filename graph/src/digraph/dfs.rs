@@ -0,0 +1,114 @@
+use super::DiGraph;
+use crate::{Edge, NodeID};
+use std::collections::{HashMap, HashSet};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DfsEvent {
+    Discover(NodeID),
+    TreeEdge(Edge),
+    BackEdge(Edge),
+    ForwardEdge(Edge),
+    CrossEdge(Edge),
+    Finish(NodeID),
+}
+
+// 用显式栈代替递归，深图也不会栈溢出
+pub struct Dfs<'a, V> {
+    graph: &'a DiGraph<V>,
+    roots: Vec<NodeID>,
+    // 栈帧：节点及其下一个待检查的邻接点下标
+    stack: Vec<(NodeID, usize)>,
+    discovered: HashMap<NodeID, usize>,
+    finished: HashSet<NodeID>,
+    pending: Option<DfsEvent>,
+}
+
+impl<'a, V> Dfs<'a, V> {
+    pub(super) fn new(graph: &'a DiGraph<V>, mut roots: Vec<NodeID>) -> Self {
+        // 根按给定顺序出栈
+        roots.reverse();
+
+        Self {
+            graph,
+            roots,
+            stack: Vec::new(),
+            discovered: HashMap::with_capacity(graph.node_count()),
+            finished: HashSet::with_capacity(graph.node_count()),
+            pending: None,
+        }
+    }
+
+    fn discover(&mut self, id: NodeID) -> DfsEvent {
+        self.discovered.insert(id, self.discovered.len());
+        self.stack.push((id, 0));
+
+        DfsEvent::Discover(id)
+    }
+
+    pub fn preorder(self) -> impl Iterator<Item = NodeID> + 'a {
+        self.filter_map(|event| match event {
+            DfsEvent::Discover(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn postorder(self) -> impl Iterator<Item = NodeID> + 'a {
+        self.filter_map(|event| match event {
+            DfsEvent::Finish(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn reverse_postorder(self) -> Vec<NodeID> {
+        let mut order: Vec<_> = self.postorder().collect();
+        order.reverse();
+        order
+    }
+}
+
+impl<V> Iterator for Dfs<'_, V> {
+    type Item = DfsEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.take() {
+            return Some(event);
+        }
+
+        let Some((id, next)) = self.stack.last_mut() else {
+            // 当前树已搜索完毕，从下一个未发现的根继续
+            let root = loop {
+                let root = self.roots.pop()?;
+
+                if !self.discovered.contains_key(&root) {
+                    break root;
+                }
+            };
+
+            return Some(self.discover(root));
+        };
+        let id = *id;
+
+        let Some(&end) = self.graph.neighbours(id).get(*next) else {
+            // 邻接点全部检查完毕，节点出栈
+            self.stack.pop();
+            self.finished.insert(id);
+
+            return Some(DfsEvent::Finish(id));
+        };
+        *next += 1;
+
+        let event = match self.discovered.get(&end) {
+            None => {
+                self.pending = Some(self.discover(end));
+                DfsEvent::TreeEdge((id, end))
+            }
+            // 终点仍在栈中
+            Some(_) if !self.finished.contains(&end) => DfsEvent::BackEdge((id, end)),
+            // 终点是已完成的后代
+            Some(&time) if time > self.discovered[&id] => DfsEvent::ForwardEdge((id, end)),
+            Some(_) => DfsEvent::CrossEdge((id, end)),
+        };
+
+        Some(event)
+    }
+}
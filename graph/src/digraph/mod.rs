@@ -1,13 +1,25 @@
-use super::{Edge, NodeID, NodeNotInGraph};
+use super::csr::CsrGraph;
+use super::text::{FromListing, Listing, ToListing, Unweighted};
+use super::{Components, Edge, GraphError, NodeID, WeiEdge};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+mod dfs;
+mod path;
+mod scc;
+mod topological;
 
 #[cfg(test)]
 mod tests;
 
+pub use self::dfs::{Dfs, DfsEvent};
+
 struct Node<V> {
-    #[allow(dead_code)]
     element: V,
     neighbours: Vec<u32>,
+    // 反向邻接表，记录所有指向该节点的边的起点
+    predecessors: Vec<u32>,
 }
 
 impl<V> Node<V> {
@@ -15,6 +27,7 @@ impl<V> Node<V> {
         Self {
             element: elt,
             neighbours: Vec::new(),
+            predecessors: Vec::new(),
         }
     }
 }
@@ -25,10 +38,6 @@ pub struct DiGraph<V = ()> {
 
 // private
 impl<V> DiGraph<V> {
-    fn add_node(&mut self, id: NodeID, node: V) {
-        self.adj_table.entry(id).or_insert(Node::new(node));
-    }
-
     fn neighbours(&self, id: NodeID) -> &[NodeID] {
         self.adj_table
             .get(&id)
@@ -36,15 +45,27 @@ impl<V> DiGraph<V> {
             .unwrap()
     }
 
-    fn bfs(&self, src: NodeID, edge_to: &mut HashMap<NodeID, NodeID>) {
+    fn predecessors(&self, id: NodeID) -> &[NodeID] {
+        self.adj_table
+            .get(&id)
+            .map(|node| node.predecessors.as_slice())
+            .unwrap()
+    }
+
+    fn bfs(&self, src: NodeID, dest: Option<NodeID>, edge_to: &mut HashMap<NodeID, NodeID>) {
         let mut marked = HashSet::with_capacity(self.node_count());
         let mut queue = VecDeque::new();
 
         marked.insert(src);
         queue.push_front(src);
 
-        // 搜索会遍历所有节点
+        // 未指定终点时遍历所有可达节点
         while let Some(id) = queue.pop_back() {
+            // 终点出队时路径已确定，提前结束
+            if Some(id) == dest {
+                break;
+            }
+
             for &neighbour in self.neighbours(id) {
                 // 若相邻点未标记，则压入队列
                 if marked.insert(neighbour) {
@@ -66,6 +87,23 @@ impl<V> DiGraph<V> {
         }
     }
 
+    // 节点已存在时不做修改，返回 false
+    pub fn add_node(&mut self, id: NodeID, elt: V) -> bool {
+        if self.contains_id(id) {
+            return false;
+        }
+
+        self.adj_table.insert(id, Node::new(elt));
+        true
+    }
+
+    // 替换节点的值，返回旧值
+    pub fn update_node(&mut self, id: NodeID, elt: V) -> Result<V, GraphError> {
+        self.node_weight_mut(id)
+            .map(|old| mem::replace(old, elt))
+            .ok_or(GraphError::NodeNotInGraph(id))
+    }
+
     pub fn add_edge(&mut self, edge: Edge, start: V, end: V) {
         self.add_node(edge.0, start);
         self.add_node(edge.1, end);
@@ -73,36 +111,121 @@ impl<V> DiGraph<V> {
         self.adj_table.entry(edge.0).and_modify(|node| {
             node.neighbours.push(edge.1);
         });
+        self.adj_table.entry(edge.1).and_modify(|node| {
+            node.predecessors.push(edge.0);
+        });
+    }
+
+    // 删除节点及所有与之关联的边
+    pub fn remove_node(&mut self, id: NodeID) -> Option<V> {
+        let node = self.adj_table.remove(&id)?;
+
+        for node in self.adj_table.values_mut() {
+            node.neighbours.retain(|&end| end != id);
+            node.predecessors.retain(|&start| start != id);
+        }
+
+        Some(node.element)
+    }
+
+    // 删除一条边；存在平行边时只删除其中一条
+    pub fn remove_edge(&mut self, edge: Edge) -> bool {
+        let Some(node) = self.adj_table.get_mut(&edge.0) else {
+            return false;
+        };
+
+        let Some(i) = node.neighbours.iter().position(|&end| end == edge.1) else {
+            return false;
+        };
+
+        node.neighbours.remove(i);
+
+        let node = self.adj_table.get_mut(&edge.1).unwrap();
+        let i = node
+            .predecessors
+            .iter()
+            .position(|&start| start == edge.0)
+            .unwrap();
+        node.predecessors.remove(i);
+
+        true
+    }
+
+    pub fn retain_nodes(&mut self, mut f: impl FnMut(NodeID, &V) -> bool) {
+        let removed: HashSet<_> = self
+            .adj_table
+            .iter()
+            .filter(|(&id, node)| !f(id, &node.element))
+            .map(|(&id, _)| id)
+            .collect();
+
+        self.adj_table.retain(|id, _| !removed.contains(id));
+
+        for node in self.adj_table.values_mut() {
+            node.neighbours.retain(|end| !removed.contains(end));
+            node.predecessors.retain(|start| !removed.contains(start));
+        }
+    }
+
+    pub fn retain_edges(&mut self, mut f: impl FnMut(Edge) -> bool) {
+        for (&start, node) in &mut self.adj_table {
+            node.neighbours.retain(|&end| f((start, end)));
+        }
+
+        // 按保留下来的正向边重建反向邻接表
+        let mut predecessors: HashMap<NodeID, Vec<NodeID>> = HashMap::new();
+
+        for (&start, node) in &self.adj_table {
+            for &end in &node.neighbours {
+                predecessors.entry(end).or_default().push(start);
+            }
+        }
+
+        for (id, node) in &mut self.adj_table {
+            node.predecessors = predecessors.remove(id).unwrap_or_default();
+        }
     }
 
     pub fn contains_id(&self, id: NodeID) -> bool {
         self.adj_table.contains_key(&id)
     }
 
+    pub fn node_weight(&self, id: NodeID) -> Option<&V> {
+        self.adj_table.get(&id).map(|node| &node.element)
+    }
+
+    pub fn node_weight_mut(&mut self, id: NodeID) -> Option<&mut V> {
+        self.adj_table.get_mut(&id).map(|node| &mut node.element)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeID, &V)> + '_ {
+        self.adj_table.iter().map(|(&id, node)| (id, &node.element))
+    }
+
     pub fn node_count(&self) -> usize {
         self.adj_table.len()
     }
 
     pub fn edge_count(&self) -> usize {
         self.adj_table
-            .iter()
-            .map(|(_, node)| node.neighbours.len())
+            .values()
+            .map(|node| node.neighbours.len())
             .sum()
     }
 
-    pub fn shortest_path(
-        &self,
-        src: NodeID,
-        dest: NodeID,
-    ) -> Result<VecDeque<NodeID>, NodeNotInGraph> {
+    pub fn shortest_path(&self, src: NodeID, dest: NodeID) -> Result<VecDeque<NodeID>, GraphError> {
         // 确认起点和终点都存在
         if let Some(id) = [src, dest].into_iter().find(|&id| !self.contains_id(id)) {
-            return Err(NodeNotInGraph(id));
+            return Err(GraphError::NodeNotInGraph(id));
         }
 
         let mut edge_to = HashMap::with_capacity(self.node_count());
 
-        self.bfs(src, &mut edge_to);
+        self.bfs(src, Some(dest), &mut edge_to);
+
+        if src != dest && !edge_to.contains_key(&dest) {
+            return Err(GraphError::NoPath(src, dest));
+        }
 
         let mut path = VecDeque::new();
         let mut id = dest;
@@ -110,11 +233,227 @@ impl<V> DiGraph<V> {
         // 在广搜得到的路径上，回溯至起点
         while id != src {
             path.push_front(id);
-            id = *edge_to.get(&id).unwrap();
+            id = edge_to[&id];
         }
 
         path.push_front(src);
 
-        return Ok(path);
+        Ok(path)
+    }
+
+    // 从 src 出发可达的所有节点，包括 src 自身
+    pub fn reachable(&self, src: NodeID) -> Result<HashSet<NodeID>, GraphError> {
+        if !self.contains_id(src) {
+            return Err(GraphError::NodeNotInGraph(src));
+        }
+
+        let mut edge_to = HashMap::with_capacity(self.node_count());
+
+        self.bfs(src, None, &mut edge_to);
+
+        let mut reached: HashSet<_> = edge_to.into_keys().collect();
+        reached.insert(src);
+
+        Ok(reached)
+    }
+
+    // 从两端同时广搜，适合大规模稀疏图
+    pub fn bidirectional_shortest_path(
+        &self,
+        src: NodeID,
+        dest: NodeID,
+    ) -> Result<VecDeque<NodeID>, GraphError> {
+        if let Some(id) = [src, dest].into_iter().find(|&id| !self.contains_id(id)) {
+            return Err(GraphError::NodeNotInGraph(id));
+        }
+
+        path::bidirectional(self, src, dest, |_| false)
+            .map(VecDeque::from)
+            .ok_or(GraphError::NoPath(src, dest))
+    }
+
+    // Yen 算法：按长度升序返回至多 k 条无环路径
+    pub fn k_shortest_paths(
+        &self,
+        src: NodeID,
+        dest: NodeID,
+        k: usize,
+    ) -> Result<Vec<VecDeque<NodeID>>, GraphError> {
+        if let Some(id) = [src, dest].into_iter().find(|&id| !self.contains_id(id)) {
+            return Err(GraphError::NodeNotInGraph(id));
+        }
+
+        Ok(path::yen(self, src, dest, k)
+            .into_iter()
+            .map(VecDeque::from)
+            .collect())
+    }
+}
+
+impl<V> Default for DiGraph<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Index<NodeID> for DiGraph<V> {
+    type Output = V;
+
+    fn index(&self, id: NodeID) -> &V {
+        self.node_weight(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+impl<V> IndexMut<NodeID> for DiGraph<V> {
+    fn index_mut(&mut self, id: NodeID) -> &mut V {
+        self.node_weight_mut(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+// 深度优先搜索方法
+impl<V> DiGraph<V> {
+    pub fn dfs(&self, root: NodeID) -> Result<Dfs<'_, V>, GraphError> {
+        self.contains_id(root)
+            .then(|| Dfs::new(self, vec![root]))
+            .ok_or(GraphError::NodeNotInGraph(root))
+    }
+
+    // 按编号顺序选取根，遍历全图
+    pub fn dfs_all(&self) -> Dfs<'_, V> {
+        let mut roots: Vec<_> = self.adj_table.keys().copied().collect();
+        roots.sort_unstable();
+
+        Dfs::new(self, roots)
+    }
+}
+
+// 拓扑排序方法
+impl<V> DiGraph<V> {
+    pub fn topological_order(&self) -> Result<Vec<NodeID>, GraphError> {
+        topological::kahn(self)
+    }
+
+    pub fn dfs_topological_order(&self) -> Result<Vec<NodeID>, GraphError> {
+        topological::reverse_postorder(self)
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        !self
+            .dfs_all()
+            .any(|event| matches!(event, DfsEvent::BackEdge(_)))
+    }
+}
+
+// 强连通分量方法
+impl<V> DiGraph<V> {
+    pub fn tarjan_scc(&self) -> Components {
+        scc::tarjan(self)
+    }
+
+    pub fn kosaraju_scc(&self) -> Components {
+        scc::kosaraju(self)
+    }
+
+    pub fn condensation(&self) -> DiGraph<Vec<NodeID>> {
+        scc::condensation(self)
+    }
+}
+
+// 压缩存储方法
+impl<V> DiGraph<V> {
+    // 冻结为只读的压缩稀疏行图，每条边权重为 1，按（起点，终点）升序编号
+    pub fn to_csr(&self) -> CsrGraph {
+        let mut edges: Vec<_> = self
+            .adj_table
+            .iter()
+            .flat_map(|(&start, node)| node.neighbours.iter().map(move |&end| (start, end)))
+            .collect();
+        edges.sort_unstable();
+
+        let edges = edges
+            .into_iter()
+            .enumerate()
+            .map(|(id, edge)| WeiEdge::new(id, 1, edge))
+            .collect();
+
+        CsrGraph::build(true, self.adj_table.keys().copied(), edges)
+    }
+}
+
+// 文本格式方法
+impl<V> ToListing for DiGraph<V> {
+    type Weight = i32;
+
+    fn listing(&self) -> Listing {
+        let edges = self
+            .adj_table
+            .iter()
+            .flat_map(|(&start, node)| node.neighbours.iter().map(move |&end| ((start, end), None)))
+            .collect();
+
+        Listing::new(true, self.adj_table.keys().copied().collect(), edges)
+    }
+}
+
+impl<V: Default> FromListing for DiGraph<V> {
+    type Weight = Unweighted;
+
+    const DIRECTED: bool = true;
+
+    fn from_listing(listing: Listing<Unweighted>) -> Self {
+        let mut graph = Self::new();
+
+        for id in listing.nodes {
+            graph.add_node(id, V::default());
+        }
+
+        // 无权图忽略边权
+        for (edge, _) in listing.edges {
+            graph.add_edge(edge, V::default(), V::default());
+        }
+
+        graph
+    }
+}
+
+impl<V> DiGraph<V> {
+    pub fn to_dot(&self) -> String {
+        self.write_dot([])
+    }
+
+    // 将生成树、路径等结果中的边在图中标红
+    pub fn to_dot_highlighted(&self, highlight: impl IntoIterator<Item = Edge>) -> String {
+        self.write_dot(highlight)
+    }
+
+    pub fn from_dot(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+    {
+        Self::read_dot(text)
+    }
+
+    pub fn to_edge_list(&self) -> String {
+        self.write_edge_list()
+    }
+
+    pub fn from_edge_list(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+    {
+        Self::read_edge_list(text)
+    }
+
+    pub fn to_dimacs(&self) -> String {
+        self.write_dimacs()
+    }
+
+    pub fn from_dimacs(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+    {
+        Self::read_dimacs(text)
     }
 }
@@ -0,0 +1,147 @@
+use super::DiGraph;
+use crate::{Edge, NodeID};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+// 双向广搜：每轮扩展较小的一侧边界，两侧首次相遇即为最短路径
+// blocked 返回 true 的边视为不存在
+pub(super) fn bidirectional<V>(
+    graph: &DiGraph<V>,
+    src: NodeID,
+    dest: NodeID,
+    blocked: impl Fn(Edge) -> bool,
+) -> Option<Vec<NodeID>> {
+    if src == dest {
+        return Some(vec![src]);
+    }
+
+    // 正向记录前驱，反向记录后继
+    let mut forward = HashMap::from([(src, src)]);
+    let mut backward = HashMap::from([(dest, dest)]);
+    let mut forward_frontier = vec![src];
+    let mut backward_frontier = vec![dest];
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        let mut next = Vec::new();
+
+        if forward_frontier.len() <= backward_frontier.len() {
+            for &start in &forward_frontier {
+                for &end in graph.neighbours(start) {
+                    if blocked((start, end)) || forward.contains_key(&end) {
+                        continue;
+                    }
+
+                    forward.insert(end, start);
+
+                    if backward.contains_key(&end) {
+                        return Some(join(&forward, &backward, end));
+                    }
+
+                    next.push(end);
+                }
+            }
+
+            forward_frontier = next;
+        } else {
+            for &end in &backward_frontier {
+                for &start in graph.predecessors(end) {
+                    if blocked((start, end)) || backward.contains_key(&start) {
+                        continue;
+                    }
+
+                    backward.insert(start, end);
+
+                    if forward.contains_key(&start) {
+                        return Some(join(&forward, &backward, start));
+                    }
+
+                    next.push(start);
+                }
+            }
+
+            backward_frontier = next;
+        }
+    }
+
+    None
+}
+
+// 从相遇点分别回溯至起点与终点，拼接成完整路径
+fn join(
+    forward: &HashMap<NodeID, NodeID>,
+    backward: &HashMap<NodeID, NodeID>,
+    meet: NodeID,
+) -> Vec<NodeID> {
+    let mut path = vec![meet];
+    let mut id = meet;
+
+    while forward[&id] != id {
+        id = forward[&id];
+        path.push(id);
+    }
+
+    path.reverse();
+    id = meet;
+
+    while backward[&id] != id {
+        id = backward[&id];
+        path.push(id);
+    }
+
+    path
+}
+
+// Yen 算法：以上一条路径的每个节点为偏离点，屏蔽已用的边与根路径上的节点，
+// 求偏离点到终点的最短路径，拼接后放入候选集，每轮取出最短的候选
+pub(super) fn yen<V>(graph: &DiGraph<V>, src: NodeID, dest: NodeID, k: usize) -> Vec<Vec<NodeID>> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let Some(first) = bidirectional(graph, src, dest, |_| false) else {
+        return Vec::new();
+    };
+
+    let mut paths = vec![first];
+    // 按（长度，节点序列）排序，保证结果确定
+    let mut candidates = BTreeSet::new();
+
+    while paths.len() < k {
+        let prev = paths[paths.len() - 1].clone();
+
+        for i in 0..prev.len() - 1 {
+            let spur = prev[i];
+            let root = &prev[..=i];
+
+            // 与当前根路径相同的已知路径，屏蔽其在偏离点的下一条边
+            let removed_edges: HashSet<Edge> = paths
+                .iter()
+                .filter(|path| path.len() > i + 1 && &path[..=i] == root)
+                .map(|path| (path[i], path[i + 1]))
+                .collect();
+            // 根路径上除偏离点外的节点不可再经过，保证路径无环
+            let removed_nodes: HashSet<NodeID> = root[..i].iter().copied().collect();
+
+            let spur_path = bidirectional(graph, spur, dest, |edge| {
+                removed_edges.contains(&edge)
+                    || removed_nodes.contains(&edge.0)
+                    || removed_nodes.contains(&edge.1)
+            });
+
+            if let Some(spur_path) = spur_path {
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+
+                if !paths.contains(&path) {
+                    candidates.insert((path.len(), path));
+                }
+            }
+        }
+
+        match candidates.pop_first() {
+            Some((_, path)) => paths.push(path),
+            None => break,
+        }
+    }
+
+    paths
+}
@@ -0,0 +1,135 @@
+use super::{DfsEvent, DiGraph};
+use crate::{Components, NodeID};
+use std::collections::{HashMap, HashSet};
+
+// Tarjan 算法：一次深搜，low-link 等于自身发现序的节点是分量的根
+pub(super) fn tarjan<V>(graph: &DiGraph<V>) -> Components {
+    let mut components = Components::with_capacity(graph.node_count());
+    let mut index = HashMap::with_capacity(graph.node_count());
+    let mut low = HashMap::with_capacity(graph.node_count());
+    let mut edge_to = HashMap::with_capacity(graph.node_count());
+    let mut stack = Vec::new();
+    let mut on_stack = HashSet::new();
+
+    for event in graph.dfs_all() {
+        match event {
+            DfsEvent::Discover(id) => {
+                index.insert(id, index.len());
+                low.insert(id, index[&id]);
+                stack.push(id);
+                on_stack.insert(id);
+            }
+            DfsEvent::TreeEdge((start, end)) => {
+                edge_to.insert(end, start);
+            }
+            DfsEvent::BackEdge((start, end))
+            | DfsEvent::ForwardEdge((start, end))
+            | DfsEvent::CrossEdge((start, end)) => {
+                // 只有仍在栈中的节点与起点处于同一分量
+                if on_stack.contains(&end) {
+                    let low_end = index[&end];
+                    low.entry(start).and_modify(|low| *low = low_end.min(*low));
+                }
+            }
+            DfsEvent::Finish(id) => {
+                if low[&id] == index[&id] {
+                    // 弹出以 id 为根的整个分量
+                    let component = components.open(id);
+
+                    while let Some(member) = stack.pop() {
+                        on_stack.remove(&member);
+                        components.join(member, component);
+
+                        if member == id {
+                            break;
+                        }
+                    }
+                }
+
+                // 向树中的父节点传递 low-link
+                if let Some(&parent) = edge_to.get(&id) {
+                    let low_id = low[&id];
+                    low.entry(parent).and_modify(|low| *low = low_id.min(*low));
+                }
+            }
+        }
+    }
+
+    components
+}
+
+// Kosaraju 算法：按反向图的逆后序在原图上深搜，每棵搜索树即一个分量
+pub(super) fn kosaraju<V>(graph: &DiGraph<V>) -> Components {
+    let mut reverse = DiGraph::new();
+
+    // 交换正向与反向邻接表即得到反向图
+    for (&id, node) in &graph.adj_table {
+        reverse.add_node(id, ());
+
+        let reversed = reverse.adj_table.get_mut(&id).unwrap();
+        reversed.neighbours = node.predecessors.clone();
+        reversed.predecessors = node.neighbours.clone();
+    }
+
+    let order = reverse.dfs_all().reverse_postorder();
+    let mut components = Components::with_capacity(graph.node_count());
+
+    for event in super::Dfs::new(graph, order) {
+        match event {
+            // 树边的终点与起点同属一个分量
+            DfsEvent::TreeEdge((start, end)) => {
+                let component = components.id(start).unwrap();
+                components.join(end, component);
+            }
+            // 未经树边到达的节点是新分量的根
+            DfsEvent::Discover(id) if components.id(id).is_none() => {
+                components.open(id);
+            }
+            _ => {}
+        }
+    }
+
+    components
+}
+
+// 将每个强连通分量缩为一个节点，节点值为分量内的原节点
+pub(super) fn condensation<V>(graph: &DiGraph<V>) -> DiGraph<Vec<NodeID>> {
+    let components = tarjan(graph);
+    let mut dag = DiGraph::new();
+
+    for (component, members) in components.members().into_iter().enumerate() {
+        dag.add_node(component as NodeID, members);
+    }
+
+    let mut edges = HashSet::new();
+
+    for (&start, node) in &graph.adj_table {
+        for &end in &node.neighbours {
+            let edge = (
+                components.id(start).unwrap() as NodeID,
+                components.id(end).unwrap() as NodeID,
+            );
+
+            // 丢弃分量内部的边与重复边
+            if edge.0 != edge.1 && edges.insert(edge) {
+                dag.adj_table
+                    .get_mut(&edge.0)
+                    .unwrap()
+                    .neighbours
+                    .push(edge.1);
+                dag.adj_table
+                    .get_mut(&edge.1)
+                    .unwrap()
+                    .predecessors
+                    .push(edge.0);
+            }
+        }
+    }
+
+    for node in dag.adj_table.values_mut() {
+        node.neighbours.sort_unstable();
+        node.predecessors.sort_unstable();
+    }
+
+    dag
+}
@@ -1,5 +1,6 @@
-use super::DiGraph;
-use std::collections::VecDeque;
+use super::{DfsEvent, DiGraph};
+use crate::GraphError;
+use std::collections::{HashSet, VecDeque};
 
 fn sample() -> DiGraph<()> {
     let mut digraph: DiGraph<()> = DiGraph::new();
@@ -22,4 +23,236 @@ fn shortest_path() {
     let path = VecDeque::from([0, 5]);
 
     assert_eq!(digraph.shortest_path(0, 5), Ok(path));
+    assert_eq!(digraph.shortest_path(0, 0), Ok(VecDeque::from([0])));
+    assert_eq!(digraph.shortest_path(3, 0), Err(GraphError::NoPath(3, 0)));
+    assert_eq!(
+        digraph.shortest_path(0, 6),
+        Err(GraphError::NodeNotInGraph(6))
+    );
+}
+
+#[test]
+fn bidirectional_shortest_path() {
+    let mut digraph = sample();
+
+    assert_eq!(
+        digraph.bidirectional_shortest_path(0, 4),
+        Ok(VecDeque::from([0, 2, 4]))
+    );
+    assert_eq!(
+        digraph.bidirectional_shortest_path(3, 0),
+        Err(GraphError::NoPath(3, 0))
+    );
+
+    // 反向邻接表需随删除同步更新
+    digraph.remove_edge((2, 4));
+    assert_eq!(
+        digraph.bidirectional_shortest_path(0, 4),
+        Ok(VecDeque::from([0, 2, 3, 4]))
+    );
+
+    digraph.remove_node(3);
+    assert_eq!(
+        digraph.bidirectional_shortest_path(0, 4),
+        Err(GraphError::NoPath(0, 4))
+    );
+}
+
+#[test]
+fn k_shortest_paths() {
+    let digraph = sample();
+
+    assert_eq!(
+        digraph.k_shortest_paths(0, 5, 3),
+        Ok(vec![VecDeque::from([0, 5]), VecDeque::from([0, 2, 3, 5])])
+    );
+    assert_eq!(digraph.k_shortest_paths(0, 5, 0), Ok(vec![]));
+    assert_eq!(digraph.k_shortest_paths(3, 0, 2), Ok(vec![]));
+
+    // 3 x 3 网格，只能向右或向下走，共 6 条等长路径
+    let mut grid = DiGraph::<()>::new();
+
+    for id in 0..9 {
+        if id % 3 < 2 {
+            grid.add_edge((id, id + 1), (), ());
+        }
+        if id < 6 {
+            grid.add_edge((id, id + 3), (), ());
+        }
+    }
+
+    let paths = grid.k_shortest_paths(0, 8, 10).unwrap();
+
+    assert_eq!(paths.len(), 6);
+    assert!(paths.iter().all(|path| path.len() == 5));
+    assert_eq!(paths.iter().collect::<HashSet<_>>().len(), 6);
+}
+
+#[test]
+fn reachable() {
+    let digraph = sample();
+
+    assert_eq!(digraph.reachable(3), Ok(HashSet::from([3, 4, 5])));
+    assert_eq!(digraph.reachable(0).map(|set| set.len()), Ok(6));
+    assert!(digraph.reachable(6).is_err());
+}
+
+#[test]
+fn dfs_orders() {
+    let digraph = sample();
+
+    let preorder: Vec<_> = digraph.dfs(0).unwrap().preorder().collect();
+    assert_eq!(preorder, [0, 2, 4, 1, 3, 5]);
+
+    let postorder: Vec<_> = digraph.dfs(0).unwrap().postorder().collect();
+    assert_eq!(postorder, [4, 1, 5, 3, 2, 0]);
+
+    assert_eq!(digraph.dfs(3).unwrap().reverse_postorder(), [3, 4, 5]);
+    assert_eq!(digraph.dfs_all().preorder().count(), 6);
+    assert!(digraph.dfs(6).is_err());
+}
+
+#[test]
+fn dfs_events() {
+    let mut digraph = sample();
+    digraph.add_edge((4, 2), (), ());
+
+    let events: Vec<_> = digraph.dfs(0).unwrap().collect();
+
+    assert_eq!(events[0], DfsEvent::Discover(0));
+    assert_eq!(events[1], DfsEvent::TreeEdge((0, 2)));
+    assert!(events.contains(&DfsEvent::BackEdge((4, 2))));
+    assert!(events.contains(&DfsEvent::CrossEdge((3, 4))));
+    assert!(events.contains(&DfsEvent::ForwardEdge((0, 5))));
+    assert_eq!(events.last(), Some(&DfsEvent::Finish(0)));
+}
+
+#[test]
+fn topological_order() {
+    let mut digraph = sample();
+
+    assert!(digraph.is_acyclic());
+    assert_eq!(digraph.topological_order(), Ok(vec![0, 2, 1, 3, 4, 5]));
+    assert_eq!(digraph.dfs_topological_order(), Ok(vec![0, 2, 3, 5, 1, 4]));
+
+    digraph.add_edge((4, 0), (), ());
+
+    assert!(!digraph.is_acyclic());
+    assert_eq!(
+        digraph.topological_order(),
+        Err(GraphError::Cycle(vec![0, 2, 4]))
+    );
+    assert_eq!(
+        digraph.dfs_topological_order(),
+        Err(GraphError::Cycle(vec![0, 2, 4]))
+    );
+}
+
+fn scc_sample() -> DiGraph<()> {
+    let mut digraph: DiGraph<()> = DiGraph::new();
+
+    digraph.add_edge((4, 2), (), ());
+    digraph.add_edge((2, 3), (), ());
+    digraph.add_edge((3, 2), (), ());
+    digraph.add_edge((6, 0), (), ());
+    digraph.add_edge((0, 1), (), ());
+    digraph.add_edge((2, 0), (), ());
+    digraph.add_edge((11, 12), (), ());
+    digraph.add_edge((12, 9), (), ());
+    digraph.add_edge((9, 10), (), ());
+    digraph.add_edge((9, 11), (), ());
+    digraph.add_edge((7, 9), (), ());
+    digraph.add_edge((10, 12), (), ());
+    digraph.add_edge((11, 4), (), ());
+    digraph.add_edge((4, 3), (), ());
+    digraph.add_edge((3, 5), (), ());
+    digraph.add_edge((6, 8), (), ());
+    digraph.add_edge((8, 6), (), ());
+    digraph.add_edge((5, 4), (), ());
+    digraph.add_edge((0, 5), (), ());
+    digraph.add_edge((6, 4), (), ());
+    digraph.add_edge((6, 9), (), ());
+    digraph.add_edge((7, 6), (), ());
+
+    digraph
+}
+
+#[test]
+fn strongly_connected_components() {
+    let digraph = scc_sample();
+    let expected = vec![
+        vec![0, 2, 3, 4, 5],
+        vec![1],
+        vec![6, 8],
+        vec![7],
+        vec![9, 10, 11, 12],
+    ];
+
+    for components in [digraph.tarjan_scc(), digraph.kosaraju_scc()] {
+        let mut members = components.members();
+        members.sort();
+
+        assert_eq!(components.count(), 5);
+        assert_eq!(members, expected);
+        assert!(components.connected(6, 8));
+        assert!(!components.connected(7, 6));
+    }
+}
+
+#[test]
+fn condensation() {
+    let digraph = scc_sample();
+    let dag = digraph.condensation();
+
+    assert_eq!(dag.node_count(), 5);
+    assert_eq!(dag.edge_count(), 6);
+    assert!(dag.is_acyclic());
+}
+
+#[test]
+fn node_weight() {
+    let mut digraph = DiGraph::new();
+    digraph.add_edge((0, 1), "a", "b");
+
+    assert_eq!(digraph.node_weight(1), Some(&"b"));
+    assert_eq!(digraph.node_weight(2), None);
+
+    *digraph.node_weight_mut(0).unwrap() = "c";
+    digraph[1] = "d";
+
+    let mut nodes: Vec<_> = digraph.nodes().collect();
+    nodes.sort();
+    assert_eq!(nodes, [(0, &"c"), (1, &"d")]);
+    assert_eq!(digraph[0], "c");
+}
+
+#[test]
+fn remove() {
+    let mut digraph = sample();
+
+    assert_eq!(digraph.remove_node(2), Some(()));
+    assert_eq!(digraph.remove_node(2), None);
+    assert_eq!(digraph.node_count(), 5);
+    assert_eq!(digraph.edge_count(), 4);
+
+    assert!(digraph.remove_edge((0, 5)));
+    assert!(!digraph.remove_edge((0, 5)));
+
+    digraph.retain_edges(|(start, _)| start != 3);
+    assert_eq!(digraph.edge_count(), 1);
+
+    digraph.retain_nodes(|id, _| id != 1);
+    assert_eq!(digraph.node_count(), 4);
+    assert_eq!(digraph.edge_count(), 0);
+}
+
+#[test]
+fn add_node() {
+    let mut digraph = sample();
+
+    assert!(digraph.add_node(6, ()));
+    assert!(!digraph.add_node(0, ()));
+    assert_eq!(digraph.node_count(), 7);
+    assert_eq!(digraph.edge_count(), 8);
+    assert!(digraph.shortest_path(6, 6).is_ok());
 }
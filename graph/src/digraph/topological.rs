@@ -0,0 +1,76 @@
+use super::{DfsEvent, DiGraph};
+use crate::{GraphError, NodeID};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// Kahn 算法：不断取出入度为 0 的节点，编号小者优先
+pub(super) fn kahn<V>(graph: &DiGraph<V>) -> Result<Vec<NodeID>, GraphError> {
+    let mut in_degree: HashMap<NodeID, usize> = graph.adj_table.keys().map(|&id| (id, 0)).collect();
+
+    for node in graph.adj_table.values() {
+        for end in &node.neighbours {
+            *in_degree.get_mut(end).unwrap() += 1;
+        }
+    }
+
+    let mut sources: BinaryHeap<_> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&id, _)| Reverse(id))
+        .collect();
+    let mut order = Vec::with_capacity(graph.node_count());
+
+    while let Some(Reverse(id)) = sources.pop() {
+        order.push(id);
+
+        // 删除出边，入度归零的节点成为新的源点
+        for end in graph.neighbours(id) {
+            let degree = in_degree.get_mut(end).unwrap();
+            *degree -= 1;
+
+            if *degree == 0 {
+                sources.push(Reverse(*end));
+            }
+        }
+    }
+
+    // 环上的节点入度永远不会归零
+    if order.len() < graph.node_count() {
+        return Err(reverse_postorder(graph).unwrap_err());
+    }
+
+    Ok(order)
+}
+
+// 无后向边时，深搜的逆后序即拓扑序
+pub(super) fn reverse_postorder<V>(graph: &DiGraph<V>) -> Result<Vec<NodeID>, GraphError> {
+    let mut order = Vec::with_capacity(graph.node_count());
+    let mut edge_to = HashMap::with_capacity(graph.node_count());
+
+    for event in graph.dfs_all() {
+        match event {
+            DfsEvent::TreeEdge((start, end)) => {
+                edge_to.insert(end, start);
+            }
+            DfsEvent::BackEdge((start, end)) => {
+                // 后向边的终点是起点的祖先，沿树边回溯即得环
+                let mut cycle = vec![start];
+                let mut id = start;
+
+                while id != end {
+                    id = edge_to[&id];
+                    cycle.push(id);
+                }
+
+                cycle.reverse();
+                return Err(GraphError::Cycle(cycle));
+            }
+            DfsEvent::Finish(id) => order.push(id),
+            _ => {}
+        }
+    }
+
+    order.reverse();
+
+    Ok(order)
+}
@@ -1,57 +1,115 @@
-#![feature(let_chains, map_first_last)]
-
 use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
 
+mod components;
+mod csr;
 mod digraph;
+mod path;
+mod text;
 mod ungraph;
+mod wei_digraph;
+mod weight;
 
 pub type NodeID = u32;
 pub type Edge = (NodeID, NodeID);
-pub type Weight = i32;
+pub type EdgeID = usize;
 
-#[derive(Debug, Hash, Eq)]
-pub struct WeiEdge {
+// 边的相等与哈希包含编号，同一图中端点、权重都相同的平行边也互不相等
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct WeiEdge<W = i32> {
+    pub id: EdgeID,
     pub edge: Edge,
-    pub weight: Weight,
+    pub weight: W,
 }
 
-impl PartialEq for WeiEdge {
-    fn eq(&self, other: &Self) -> bool {
-        self.weight == other.weight
+impl<W> WeiEdge<W> {
+    pub fn new(id: EdgeID, weight: W, edge: Edge) -> Self {
+        Self { id, weight, edge }
     }
-}
 
-impl PartialOrd for WeiEdge {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.weight.partial_cmp(&other.weight)
+    pub fn endpoints(&self) -> (NodeID, NodeID) {
+        self.edge
     }
-}
 
-impl Ord for WeiEdge {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.weight.cmp(&other.weight)
+    pub fn is_incident(&self, id: NodeID) -> bool {
+        self.edge.0 == id || self.edge.1 == id
     }
-}
 
-impl WeiEdge {
-    pub fn new(weight: Weight, edge: Edge) -> Self {
-        Self { weight, edge }
+    // 另一端点，自环的另一端点是自身；id 不是端点时返回 None
+    pub fn other(&self, id: NodeID) -> Option<NodeID> {
+        match self.edge {
+            (start, end) if start == id => Some(end),
+            (start, end) if end == id => Some(start),
+            _ => None,
+        }
     }
+}
 
-    pub fn other(&self, id: NodeID) -> NodeID {
-        self.edge.0 + self.edge.1 - id
+impl<W: Ord> WeiEdge<W> {
+    // 按权重比较，权重相同时按编号，供排序与优先队列使用
+    pub fn cmp_weight(&self, other: &Self) -> Ordering {
+        self.weight.cmp(&other.weight).then(self.id.cmp(&other.id))
     }
 }
 
-#[derive(PartialEq, Debug)]
-pub struct NodeNotInGraph(u32);
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum GraphError {
+    NodeNotInGraph(NodeID),
+    // 起点到终点不可达
+    NoPath(NodeID, NodeID),
+    Cycle(Vec<NodeID>),
+    NegativeCycle(Vec<NodeID>),
+    // 无向图中的奇环，说明图不是二分图
+    OddCycle(Vec<NodeID>),
+    EmptyGraph,
+    // 文本解析失败：行号与原因
+    Parse(usize, String),
+    Disconnected,
+    // 算法只适用于无向图
+    Directed,
+    // 算法要求边权非负：负权边的起点与终点
+    NegativeWeight(NodeID, NodeID),
+}
 
-impl fmt::Display for NodeNotInGraph {
+impl fmt::Display for GraphError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "node {} is not in the graph", self.0)
+        match self {
+            Self::NodeNotInGraph(id) => write!(f, "node {} is not in the graph", id),
+            Self::NoPath(src, dest) => write!(f, "no path from node {} to node {}", src, dest),
+            Self::Cycle(cycle) => write!(f, "cycle {:?} in the graph", cycle),
+            Self::NegativeCycle(cycle) => write!(f, "negative cycle {:?} in the graph", cycle),
+            Self::OddCycle(cycle) => write!(f, "odd cycle {:?} in the graph", cycle),
+            Self::EmptyGraph => write!(f, "the graph is empty"),
+            Self::Parse(line, msg) => write!(f, "line {}: {}", line, msg),
+            Self::Disconnected => write!(f, "the graph is disconnected"),
+            Self::Directed => write!(f, "the graph is directed"),
+            Self::NegativeWeight(start, end) => {
+                write!(f, "negative weight on edge {} -> {}", start, end)
+            }
+        }
     }
 }
 
-pub use self::digraph::DiGraph;
-pub use self::ungraph::UnGraph;
+impl Error for GraphError {}
+
+// 供各图算法共用的邻接关系视图
+pub(crate) trait Adjacency {
+    type Weight: Weight;
+
+    fn ids(&self) -> impl Iterator<Item = NodeID> + '_;
+
+    fn node_count(&self) -> usize;
+
+    fn contains_id(&self, id: NodeID) -> bool;
+
+    fn successors(&self, id: NodeID) -> impl Iterator<Item = (NodeID, Self::Weight)> + '_;
+}
+
+pub use self::components::Components;
+pub use self::csr::{CsrGraph, CsrPaths};
+pub use self::digraph::{Dfs, DfsEvent, DiGraph};
+pub use self::path::{DistanceMatrix, ShortestPaths};
+pub use self::ungraph::{Bipartition, SpanningForest, UnGraph};
+pub use self::wei_digraph::{MaxFlow, MinCut, WeiDiGraph};
+pub use self::weight::{Total, Weight};
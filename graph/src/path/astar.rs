@@ -0,0 +1,47 @@
+use super::ShortestPaths;
+use crate::{Adjacency, GraphError, NodeID, Weight};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+// 启发函数须可采纳，即不高估到终点的真实距离；边权要求同 Dijkstra
+pub(crate) fn search<G: Adjacency>(
+    graph: &G,
+    src: NodeID,
+    dest: NodeID,
+    heuristic: impl Fn(NodeID) -> G::Weight,
+) -> Result<(VecDeque<NodeID>, G::Weight), GraphError> {
+    if let Some(id) = [src, dest].into_iter().find(|&id| !graph.contains_id(id)) {
+        return Err(GraphError::NodeNotInGraph(id));
+    }
+
+    let mut paths = ShortestPaths::new(src, graph.node_count());
+    let mut pq = BinaryHeap::new();
+
+    pq.push(Reverse((heuristic(src), src)));
+
+    // 按 已走距离 + 估计剩余距离 的顺序展开节点
+    while let Some(Reverse((estimate, closest))) = pq.pop() {
+        let dist = paths.dist_to[&closest];
+
+        if closest == dest {
+            return Ok((paths.path_to(dest).unwrap(), dist));
+        }
+
+        // 队列中的旧估计已失效，跳过
+        if estimate > dist + heuristic(closest) {
+            continue;
+        }
+
+        for (end, weight) in graph.successors(closest) {
+            if weight.is_negative() {
+                return Err(GraphError::NegativeWeight(closest, end));
+            }
+
+            if paths.relax(closest, end, dist + weight) {
+                pq.push(Reverse((dist + weight + heuristic(end), end)));
+            }
+        }
+    }
+
+    Err(GraphError::NoPath(src, dest))
+}
@@ -0,0 +1,94 @@
+use super::ShortestPaths;
+use crate::{Adjacency, NodeID, Weight};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub(crate) fn search<G: Adjacency>(
+    graph: &G,
+    src: NodeID,
+) -> Result<ShortestPaths<G::Weight>, Vec<NodeID>> {
+    relax_all(graph, ShortestPaths::new(src, graph.node_count()))
+}
+
+// 相当于从一个以 0 权边连向所有节点的虚拟源点出发，
+// 所得距离可作为 Johnson 算法重赋权的势能
+pub(crate) fn potentials<G: Adjacency>(
+    graph: &G,
+) -> Result<HashMap<NodeID, G::Weight>, Vec<NodeID>> {
+    let Some(first) = graph.ids().next() else {
+        return Ok(HashMap::new());
+    };
+    let mut paths = ShortestPaths::new(first, graph.node_count());
+
+    for id in graph.ids() {
+        paths.dist_to.insert(id, G::Weight::zero());
+    }
+
+    relax_all(graph, paths).map(|paths| paths.dist_to)
+}
+
+// 逐轮松弛所有边，直至没有更新
+fn relax_all<G: Adjacency>(
+    graph: &G,
+    mut paths: ShortestPaths<G::Weight>,
+) -> Result<ShortestPaths<G::Weight>, Vec<NodeID>> {
+    let mut round = 0;
+
+    loop {
+        let mut updated = false;
+        round += 1;
+
+        for start in graph.ids() {
+            let Some(dist) = paths.dist_to(start) else {
+                continue;
+            };
+
+            for (end, weight) in graph.successors(start) {
+                updated |= paths.relax(start, end, dist + weight);
+            }
+        }
+
+        if !updated {
+            return Ok(paths);
+        }
+
+        // 第 V 轮后仍有更新，说明存在负权环；
+        // 继续松弛直到最短路径树中出现环
+        if round >= graph.node_count() {
+            if let Some(cycle) = paths.negative_cycle() {
+                return Err(cycle);
+            }
+        }
+    }
+}
+
+// 基于队列的实现：只有距离变化的节点才需要重新松弛其出边
+pub(crate) fn spfa<G: Adjacency>(
+    graph: &G,
+    src: NodeID,
+) -> Result<ShortestPaths<G::Weight>, Vec<NodeID>> {
+    let mut paths = ShortestPaths::new(src, graph.node_count());
+    let mut queue = VecDeque::from([src]);
+    let mut on_queue = HashSet::from([src]);
+    let mut relaxations = 0;
+
+    while let Some(start) = queue.pop_front() {
+        on_queue.remove(&start);
+        let dist = paths.dist_to[&start];
+
+        for (end, weight) in graph.successors(start) {
+            if paths.relax(start, end, dist + weight) && on_queue.insert(end) {
+                queue.push_back(end);
+            }
+
+            // 每松弛 V 次，检查一次最短路径树中是否有环
+            relaxations += 1;
+            if relaxations % graph.node_count() == 0 {
+                if let Some(cycle) = paths.negative_cycle() {
+                    return Err(cycle);
+                }
+            }
+        }
+    }
+
+    Ok(paths)
+}
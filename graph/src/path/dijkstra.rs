@@ -0,0 +1,39 @@
+use super::ShortestPaths;
+use crate::{Adjacency, GraphError, NodeID, Weight};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// 要求从起点可达的边权非负，遇到负权边即返回错误，否则负权环会使搜索无法结束
+pub(crate) fn search<G: Adjacency>(
+    graph: &G,
+    src: NodeID,
+) -> Result<ShortestPaths<G::Weight>, GraphError> {
+    if !graph.contains_id(src) {
+        return Err(GraphError::NodeNotInGraph(src));
+    }
+
+    let mut paths = ShortestPaths::new(src, graph.node_count());
+    let mut pq = BinaryHeap::new();
+
+    pq.push(Reverse((G::Weight::zero(), src)));
+
+    // 不断取出距起点最近的节点
+    while let Some(Reverse((dist, closest))) = pq.pop() {
+        // 队列中的旧距离已失效，跳过
+        if dist > paths.dist_to[&closest] {
+            continue;
+        }
+
+        for (end, weight) in graph.successors(closest) {
+            if weight.is_negative() {
+                return Err(GraphError::NegativeWeight(closest, end));
+            }
+
+            if paths.relax(closest, end, dist + weight) {
+                pq.push(Reverse((dist + weight, end)));
+            }
+        }
+    }
+
+    Ok(paths)
+}
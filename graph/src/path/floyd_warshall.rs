@@ -0,0 +1,69 @@
+use super::DistanceMatrix;
+use crate::{Adjacency, NodeID, Weight};
+
+pub(crate) fn search<G: Adjacency>(graph: &G) -> Result<DistanceMatrix<G::Weight>, Vec<NodeID>> {
+    let mut matrix = DistanceMatrix::new(graph);
+    let n = matrix.ids.len();
+
+    // 以边权初始化，平行边取最小者
+    for i in 0..n {
+        for (end, weight) in graph.successors(matrix.ids[i]) {
+            let j = matrix.index_of[&end];
+            let cell = matrix.cell(i, j);
+
+            if matrix.dist_to[cell].is_none_or(|dist| weight < dist) {
+                matrix.dist_to[cell] = Some(weight);
+                matrix.edge_to[cell] = Some(i);
+            }
+        }
+    }
+
+    // 逐个允许节点 k 作为中转点；
+    // 节点到自身的距离为负，说明它在负权环上，须立即停止，否则负距离会越叠越大直至溢出
+    for k in 0..n {
+        for i in 0..n {
+            let Some(dist_ik) = matrix.dist_to[matrix.cell(i, k)] else {
+                continue;
+            };
+
+            for j in 0..n {
+                let Some(dist_kj) = matrix.dist_to[matrix.cell(k, j)] else {
+                    continue;
+                };
+                let cell = matrix.cell(i, j);
+
+                if matrix.dist_to[cell].is_none_or(|dist| dist_ik + dist_kj < dist) {
+                    matrix.dist_to[cell] = Some(dist_ik + dist_kj);
+                    matrix.edge_to[cell] = matrix.edge_to[matrix.cell(k, j)];
+                }
+            }
+        }
+
+        if let Some(i) = (0..n)
+            .find(|&i| matrix.dist_to[matrix.cell(i, i)].is_some_and(|dist| dist.is_negative()))
+        {
+            return Err(negative_cycle(&matrix, i));
+        }
+    }
+
+    Ok(matrix)
+}
+
+// 沿第 i 行的前驱回溯，前驱链首次回到走过的节点时即找到环，按边的方向返回
+fn negative_cycle<W: Weight>(matrix: &DistanceMatrix<W>, i: usize) -> Vec<NodeID> {
+    let mut step_of = vec![None; matrix.ids.len()];
+    let mut walk = Vec::new();
+    let mut v = i;
+
+    while step_of[v].is_none() {
+        step_of[v] = Some(walk.len());
+        walk.push(v);
+        v = matrix.edge_to[matrix.cell(i, v)].unwrap();
+    }
+
+    walk[step_of[v].unwrap()..]
+        .iter()
+        .rev()
+        .map(|&v| matrix.ids[v])
+        .collect()
+}
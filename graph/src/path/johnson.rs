@@ -0,0 +1,59 @@
+use super::{bellman_ford, dijkstra, DistanceMatrix};
+use crate::{Adjacency, NodeID, Weight};
+use std::collections::HashMap;
+
+// 以势能重赋权：w'(u, v) = w(u, v) + h(u) - h(v) >= 0，
+// 浮点舍入得到的极小负数截为零
+struct Reweighted<'a, G: Adjacency> {
+    graph: &'a G,
+    potential: &'a HashMap<NodeID, G::Weight>,
+}
+
+impl<G: Adjacency> Adjacency for Reweighted<'_, G> {
+    type Weight = G::Weight;
+
+    fn ids(&self) -> impl Iterator<Item = NodeID> + '_ {
+        self.graph.ids()
+    }
+
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    fn contains_id(&self, id: NodeID) -> bool {
+        self.graph.contains_id(id)
+    }
+
+    fn successors(&self, id: NodeID) -> impl Iterator<Item = (NodeID, G::Weight)> + '_ {
+        self.graph.successors(id).map(move |(end, weight)| {
+            let weight = weight + self.potential[&id] - self.potential[&end];
+            (end, weight.max(G::Weight::zero()))
+        })
+    }
+}
+
+// 一次 Bellman-Ford 求势能，再从每个节点出发跑 Dijkstra
+pub(crate) fn search<G: Adjacency>(graph: &G) -> Result<DistanceMatrix<G::Weight>, Vec<NodeID>> {
+    let potential = bellman_ford::potentials(graph)?;
+    let reweighted = Reweighted {
+        graph,
+        potential: &potential,
+    };
+    let mut matrix = DistanceMatrix::new(graph);
+
+    for i in 0..matrix.ids.len() {
+        let src = matrix.ids[i];
+        // 重赋权后边权非负，不会出错
+        let paths = dijkstra::search(&reweighted, src).unwrap();
+
+        for (&dest, &dist) in paths.distances() {
+            let cell = matrix.cell(i, matrix.index_of[&dest]);
+
+            // 还原为原图中的距离
+            matrix.dist_to[cell] = Some(dist - potential[&src] + potential[&dest]);
+            matrix.edge_to[cell] = paths.edge_to(dest).map(|prev| matrix.index_of[&prev]);
+        }
+    }
+
+    Ok(matrix)
+}
@@ -0,0 +1,191 @@
+use super::{Adjacency, NodeID, Weight};
+use std::collections::{HashMap, VecDeque};
+
+pub(crate) mod astar;
+pub(crate) mod bellman_ford;
+pub(crate) mod dijkstra;
+pub(crate) mod floyd_warshall;
+pub(crate) mod johnson;
+#[cfg(feature = "parallel")]
+pub(crate) mod parallel;
+
+#[derive(Debug)]
+pub struct ShortestPaths<W = i32> {
+    src: NodeID,
+    dist_to: HashMap<NodeID, W>,
+    edge_to: HashMap<NodeID, NodeID>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    fn new(src: NodeID, capacity: usize) -> Self {
+        let mut dist_to = HashMap::with_capacity(capacity);
+        dist_to.insert(src, W::zero());
+
+        Self {
+            src,
+            dist_to,
+            edge_to: HashMap::with_capacity(capacity),
+        }
+    }
+
+    // 松弛边 start -> end，距离更短时更新并返回 true
+    fn relax(&mut self, start: NodeID, end: NodeID, dist: W) -> bool {
+        if self.dist_to.get(&end).is_none_or(|&old| dist < old) {
+            self.dist_to.insert(end, dist);
+            self.edge_to.insert(end, start);
+            true
+        } else {
+            false
+        }
+    }
+
+    // 最短路径树中的环必为负权环，按边的方向返回环上节点
+    fn negative_cycle(&self) -> Option<Vec<NodeID>> {
+        let mut walk_of = HashMap::with_capacity(self.edge_to.len());
+
+        for (walk, &start) in self.edge_to.keys().enumerate() {
+            let mut id = start;
+
+            // 沿前驱回溯，遇到本次回溯走过的节点即成环
+            loop {
+                match walk_of.insert(id, walk) {
+                    Some(prev) if prev == walk => {
+                        let mut cycle = vec![id];
+                        let mut prev = self.edge_to[&id];
+
+                        while prev != id {
+                            cycle.push(prev);
+                            prev = self.edge_to[&prev];
+                        }
+
+                        cycle.reverse();
+                        return Some(cycle);
+                    }
+                    Some(_) => break,
+                    None => {}
+                }
+
+                match self.edge_to.get(&id) {
+                    Some(&prev) => id = prev,
+                    None => break,
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    pub fn src(&self) -> NodeID {
+        self.src
+    }
+
+    pub fn dist_to(&self, id: NodeID) -> Option<W> {
+        self.dist_to.get(&id).copied()
+    }
+
+    pub fn has_path_to(&self, id: NodeID) -> bool {
+        self.dist_to.contains_key(&id)
+    }
+
+    pub fn edge_to(&self, id: NodeID) -> Option<NodeID> {
+        self.edge_to.get(&id).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<NodeID, W> {
+        &self.dist_to
+    }
+
+    pub fn predecessors(&self) -> &HashMap<NodeID, NodeID> {
+        &self.edge_to
+    }
+
+    pub fn path_to(&self, dest: NodeID) -> Option<VecDeque<NodeID>> {
+        if !self.has_path_to(dest) {
+            return None;
+        }
+
+        let mut path = VecDeque::new();
+        let mut id = dest;
+
+        // 沿最短路径树回溯至起点
+        while id != self.src {
+            path.push_front(id);
+            id = self.edge_to[&id];
+        }
+
+        path.push_front(self.src);
+
+        Some(path)
+    }
+}
+
+// 全源最短路径：节点按编号升序排列，行为起点，列为终点
+#[derive(Debug)]
+pub struct DistanceMatrix<W = i32> {
+    ids: Vec<NodeID>,
+    index_of: HashMap<NodeID, usize>,
+    dist_to: Vec<Option<W>>,
+    // 从行起点到列终点的最短路径上，终点的前驱下标
+    edge_to: Vec<Option<usize>>,
+}
+
+impl<W: Weight> DistanceMatrix<W> {
+    fn new<G: Adjacency<Weight = W>>(graph: &G) -> Self {
+        let mut ids: Vec<_> = graph.ids().collect();
+        ids.sort_unstable();
+
+        let n = ids.len();
+        let index_of = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut dist_to = vec![None; n * n];
+
+        for i in 0..n {
+            dist_to[i * n + i] = Some(W::zero());
+        }
+
+        Self {
+            ids,
+            index_of,
+            dist_to,
+            edge_to: vec![None; n * n],
+        }
+    }
+
+    fn cell(&self, i: usize, j: usize) -> usize {
+        i * self.ids.len() + j
+    }
+}
+
+impl<W: Weight> DistanceMatrix<W> {
+    pub fn ids(&self) -> &[NodeID] {
+        &self.ids
+    }
+
+    pub fn index_of(&self, id: NodeID) -> Option<usize> {
+        self.index_of.get(&id).copied()
+    }
+
+    pub fn dist(&self, src: NodeID, dest: NodeID) -> Option<W> {
+        let cell = self.cell(self.index_of(src)?, self.index_of(dest)?);
+
+        self.dist_to[cell]
+    }
+
+    pub fn path(&self, src: NodeID, dest: NodeID) -> Option<VecDeque<NodeID>> {
+        let i = self.index_of(src)?;
+        let mut j = self.index_of(dest)?;
+
+        self.dist_to[self.cell(i, j)]?;
+
+        let mut path = VecDeque::from([dest]);
+
+        // 沿前驱回溯至起点
+        while j != i {
+            j = self.edge_to[self.cell(i, j)].unwrap();
+            path.push_front(self.ids[j]);
+        }
+
+        Some(path)
+    }
+}
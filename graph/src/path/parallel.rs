@@ -0,0 +1,49 @@
+use super::{dijkstra, ShortestPaths};
+use crate::{Adjacency, GraphError, NodeID};
+use rayon::prelude::*;
+
+// 按层同步的广搜，距离为边数：同层节点的邻接表并行展开，再按顺序合并，结果与串行广搜一致
+pub(crate) fn bfs<G: Adjacency + Sync>(
+    graph: &G,
+    src: NodeID,
+) -> Result<ShortestPaths<usize>, GraphError> {
+    if !graph.contains_id(src) {
+        return Err(GraphError::NodeNotInGraph(src));
+    }
+
+    let mut paths = ShortestPaths::new(src, graph.node_count());
+    let mut frontier = vec![src];
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        depth += 1;
+
+        let reached: Vec<_> = frontier
+            .par_iter()
+            .flat_map_iter(|&start| graph.successors(start).map(move |(end, _)| (start, end)))
+            .collect();
+
+        // 已到达的节点距离不会更短，relax 返回 false
+        frontier = reached
+            .into_iter()
+            .filter(|&(start, end)| paths.relax(start, end, depth))
+            .map(|(_, end)| end)
+            .collect();
+    }
+
+    Ok(paths)
+}
+
+// 各起点的单源最短路径互不依赖，在线程池中并行求解，结果与 sources 一一对应
+pub(crate) fn dijkstra_batch<G: Adjacency + Sync>(
+    graph: &G,
+    sources: &[NodeID],
+) -> Result<Vec<ShortestPaths<G::Weight>>, GraphError>
+where
+    G::Weight: Send,
+{
+    sources
+        .par_iter()
+        .map(|&src| dijkstra::search(graph, src))
+        .collect()
+}
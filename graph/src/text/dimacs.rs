@@ -0,0 +1,126 @@
+use super::{error, parse_id, parse_weight, Listing};
+use crate::{GraphError, NodeID};
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+// DIMACS 最短路径格式：`c` 注释，`p sp n m` 问题行，`a u v w` 弧
+// DIMACS 节点从 1 开始编号，依次对应注释 `c ids ...` 中列出的编号，没有该注释时对应节点 0..n
+pub(crate) fn read<W: FromStr + Ord>(text: &str, directed: bool) -> Result<Listing<W>, GraphError> {
+    let mut problem: Option<(NodeID, usize)> = None;
+    let mut ids: Option<(usize, Vec<NodeID>)> = None;
+    let mut edges = Vec::new();
+    let mut last_line = 0;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let tokens: Vec<_> = line.split_whitespace().collect();
+
+        last_line = line_no;
+
+        match tokens[..] {
+            ["c", "ids", ref tokens @ ..] => {
+                if ids.is_some() {
+                    return Err(error(line_no, "duplicate id line"));
+                }
+
+                let parsed = tokens.iter().map(|token| parse_id(line_no, token));
+                ids = Some((line_no, parsed.collect::<Result<_, _>>()?));
+            }
+            [] | ["c", ..] => {}
+            ["p", "sp", n, m] => {
+                if problem.is_some() {
+                    return Err(error(line_no, "duplicate problem line"));
+                }
+
+                let m = m
+                    .parse()
+                    .map_err(|_| error(line_no, format!("invalid arc count `{}`", m)))?;
+                problem = Some((parse_id(line_no, n)?, m));
+            }
+            ["p", ..] => return Err(error(line_no, "expected `p sp n m`")),
+            ["a", start, end, weight] => {
+                let Some((n, _)) = problem else {
+                    return Err(error(line_no, "arc before problem line"));
+                };
+
+                let node = |token| match parse_id(line_no, token)? {
+                    id @ 1.. if id <= n => Ok(id - 1),
+                    id => Err(error(
+                        line_no,
+                        format!("node {} out of range 1..={}", id, n),
+                    )),
+                };
+
+                edges.push((
+                    (node(start)?, node(end)?),
+                    Some(parse_weight(line_no, weight)?),
+                ));
+            }
+            ["a", ..] => return Err(error(line_no, "expected `a u v w`")),
+            _ => return Err(error(line_no, format!("unknown line type `{}`", tokens[0]))),
+        }
+    }
+
+    let Some((n, m)) = problem else {
+        return Err(error(last_line, "missing problem line"));
+    };
+
+    if edges.len() != m {
+        return Err(error(
+            last_line,
+            format!("expected {} arcs, found {}", m, edges.len()),
+        ));
+    }
+
+    let Some((line_no, ids)) = ids else {
+        return Ok(Listing::new(directed, (0..n).collect(), edges));
+    };
+
+    if ids.len() != n as usize {
+        return Err(error(
+            line_no,
+            format!("expected {} ids, found {}", n, ids.len()),
+        ));
+    }
+
+    let edges = edges
+        .into_iter()
+        .map(|((start, end), weight)| ((ids[start as usize], ids[end as usize]), weight))
+        .collect();
+
+    Ok(Listing::new(directed, ids, edges))
+}
+
+// 无向图的每条边只写出一条弧；无权图的弧权为 1
+// 节点按编号升序依次编为 1..=n；编号不是 0..n 时，在注释 `c ids ...` 中记下原编号
+pub(crate) fn write<W: Display + Ord>(listing: &Listing<W>) -> String {
+    let index = |id| listing.nodes.binary_search(&id).unwrap() + 1;
+    let mut text = String::new();
+
+    if !listing
+        .nodes
+        .iter()
+        .copied()
+        .eq(0..listing.nodes.len() as NodeID)
+    {
+        text.push_str("c ids");
+
+        for id in &listing.nodes {
+            write!(text, " {}", id).unwrap();
+        }
+
+        text.push('\n');
+    }
+
+    writeln!(text, "p sp {} {}", listing.nodes.len(), listing.edges.len()).unwrap();
+
+    for &((start, end), ref weight) in &listing.edges {
+        match weight {
+            Some(weight) => writeln!(text, "a {} {} {}", index(start), index(end), weight),
+            None => writeln!(text, "a {} {} 1", index(start), index(end)),
+        }
+        .unwrap();
+    }
+
+    text
+}
@@ -0,0 +1,333 @@
+use super::{error, parse_id, parse_weight, Listing};
+use crate::{GraphError, NodeID};
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+#[derive(PartialEq, Debug, Clone)]
+enum Token {
+    Id(String),
+    // `->` 或 `--`
+    EdgeOp(bool),
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Equal,
+    Comma,
+    Semicolon,
+}
+
+// 词法分析，每个记号附带所在行号
+fn lex(text: &str) -> Result<Vec<(usize, Token)>, GraphError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\n' => {
+                line += 1;
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            '#' => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let start = line;
+
+                loop {
+                    match chars.next() {
+                        Some('*') if chars.next_if_eq(&'/').is_some() => break,
+                        Some('\n') => line += 1,
+                        Some(_) => {}
+                        None => return Err(error(start, "unterminated comment")),
+                    }
+                }
+
+                continue;
+            }
+            '-' if chars.next_if_eq(&'>').is_some() => Token::EdgeOp(true),
+            '-' if chars.next_if_eq(&'-').is_some() => Token::EdgeOp(false),
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '=' => Token::Equal,
+            ',' => Token::Comma,
+            ';' => Token::Semicolon,
+            '"' => {
+                let start = line;
+                let mut id = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') if chars.next_if_eq(&'"').is_some() => id.push('"'),
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            id.push(c);
+                        }
+                        None => return Err(error(start, "unterminated string")),
+                    }
+                }
+
+                Token::Id(id)
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let mut id = String::from(c);
+
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+                {
+                    id.push(c);
+                }
+
+                Token::Id(id)
+            }
+            c => return Err(error(line, format!("unexpected character `{}`", c))),
+        };
+
+        tokens.push((line, token));
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    // 当前记号所在行；读完时取最后一个记号的行
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |&(line, _)| line)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, expected: &Token) -> bool {
+        let matched = self.peek() == Some(expected);
+
+        if matched {
+            self.pos += 1;
+        }
+
+        matched
+    }
+
+    fn expect_id(&mut self, what: &str) -> Result<String, GraphError> {
+        let line = self.line();
+
+        match self.next() {
+            Some(Token::Id(id)) => Ok(id),
+            _ => Err(error(line, format!("expected {}", what))),
+        }
+    }
+
+    // `[k = v, ...]`，分隔符可省略
+    fn attributes(&mut self) -> Result<Vec<(String, String)>, GraphError> {
+        let mut attributes = Vec::new();
+
+        if !self.eat(&Token::LBracket) {
+            return Ok(attributes);
+        }
+
+        while !self.eat(&Token::RBracket) {
+            let key = self.expect_id("attribute name or `]`")?;
+
+            if !self.eat(&Token::Equal) {
+                return Err(error(self.line(), "expected `=`"));
+            }
+
+            let value = self.expect_id("attribute value")?;
+            attributes.push((key, value));
+
+            if !self.eat(&Token::Comma) {
+                self.eat(&Token::Semicolon);
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+// 支持 DOT 的一个子集：节点名须为非负整数，边权取自边的 label 或 weight 属性，
+// 不支持子图；全局属性语句与节点属性被忽略
+pub(crate) fn read<W: FromStr + Ord + Clone>(
+    text: &str,
+    directed: bool,
+) -> Result<Listing<W>, GraphError> {
+    let mut parser = Parser {
+        tokens: lex(text)?,
+        pos: 0,
+    };
+    let keyword = if directed { "digraph" } else { "graph" };
+
+    if matches!(parser.peek(), Some(Token::Id(id)) if id == "strict") {
+        parser.pos += 1;
+    }
+
+    let line = parser.line();
+
+    if parser.expect_id(&format!("`{}`", keyword))? != keyword {
+        return Err(error(line, format!("expected `{}`", keyword)));
+    }
+
+    if matches!(parser.peek(), Some(Token::Id(_))) {
+        parser.pos += 1;
+    }
+
+    if !parser.eat(&Token::LBrace) {
+        return Err(error(parser.line(), "expected `{`"));
+    }
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    loop {
+        let line = parser.line();
+
+        let id = match parser.next() {
+            Some(Token::RBrace) => break,
+            Some(Token::Semicolon) => continue,
+            Some(Token::Id(id)) => id,
+            Some(Token::LBrace) => return Err(error(line, "subgraphs are not supported")),
+            Some(_) => return Err(error(line, "expected a statement")),
+            None => return Err(error(line, "expected `}`")),
+        };
+
+        // 全局属性
+        if matches!(id.as_str(), "graph" | "node" | "edge")
+            && parser.peek() == Some(&Token::LBracket)
+        {
+            parser.attributes()?;
+            continue;
+        }
+        if parser.eat(&Token::Equal) {
+            parser.expect_id("attribute value")?;
+            continue;
+        }
+        if id == "subgraph" {
+            return Err(error(line, "subgraphs are not supported"));
+        }
+
+        let mut chain = vec![parse_id(line, &id)?];
+
+        while let Some(&Token::EdgeOp(arrow)) = parser.peek() {
+            if arrow != directed {
+                let op = if arrow { "->" } else { "--" };
+                return Err(error(parser.line(), format!("`{}` in {}", op, keyword)));
+            }
+
+            parser.pos += 1;
+
+            let line = parser.line();
+            let end = parser.expect_id("node id")?;
+            chain.push(parse_id(line, &end)?);
+        }
+
+        let line = parser.line();
+        let attributes = parser.attributes()?;
+
+        // 节点语句的属性（如 label）与边权无关，忽略
+        if chain.len() == 1 {
+            nodes.push(chain[0]);
+            continue;
+        }
+
+        let mut weight = None;
+
+        for (key, value) in attributes {
+            if key == "label" || key == "weight" {
+                weight = Some(parse_weight(line, &value)?);
+            }
+        }
+
+        for pair in chain.windows(2) {
+            edges.push(((pair[0], pair[1]), weight.clone()));
+        }
+    }
+
+    if parser.peek().is_some() {
+        return Err(error(parser.line(), "unexpected content after `}`"));
+    }
+
+    Ok(Listing::new(directed, nodes, edges))
+}
+
+// 下标满足 highlighted 的边及其端点标红
+pub(crate) fn write<W: Display + Ord>(
+    listing: &Listing<W>,
+    highlighted: impl Fn(usize) -> bool,
+) -> String {
+    let highlight: HashSet<_> = (0..listing.edges.len())
+        .filter(|&i| highlighted(i))
+        .collect();
+    let marked: HashSet<NodeID> = highlight
+        .iter()
+        .flat_map(|&i| {
+            let (start, end) = listing.edges[i].0;
+            [start, end]
+        })
+        .collect();
+    let (keyword, op) = if listing.directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut text = String::new();
+
+    writeln!(text, "{} {{", keyword).unwrap();
+
+    for id in &listing.nodes {
+        if marked.contains(id) {
+            writeln!(text, "    {} [color=red];", id).unwrap();
+        } else {
+            writeln!(text, "    {};", id).unwrap();
+        }
+    }
+
+    for (i, (edge, weight)) in listing.edges.iter().enumerate() {
+        let mut attributes = Vec::new();
+
+        if let Some(weight) = weight {
+            attributes.push(format!("label={}", weight));
+        }
+        if highlight.contains(&i) {
+            attributes.push("color=red, penwidth=2".to_string());
+        }
+
+        write!(text, "    {} {} {}", edge.0, op, edge.1).unwrap();
+
+        if !attributes.is_empty() {
+            write!(text, " [{}]", attributes.join(", ")).unwrap();
+        }
+
+        writeln!(text, ";").unwrap();
+    }
+
+    writeln!(text, "}}").unwrap();
+
+    text
+}
@@ -0,0 +1,50 @@
+use super::{error, parse_id, parse_weight, Listing};
+use crate::GraphError;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+// 每行 `u v [w]` 表示一条边，单独的 `u` 表示孤立节点，`#` 之后为注释
+pub(crate) fn read<W: FromStr + Ord>(text: &str, directed: bool) -> Result<Listing<W>, GraphError> {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let content = line.split('#').next().unwrap();
+        let tokens: Vec<_> = content.split_whitespace().collect();
+
+        match tokens[..] {
+            [] => {}
+            [id] => nodes.push(parse_id(line_no, id)?),
+            [start, end] => {
+                let edge = (parse_id(line_no, start)?, parse_id(line_no, end)?);
+                edges.push((edge, None));
+            }
+            [start, end, weight] => {
+                let edge = (parse_id(line_no, start)?, parse_id(line_no, end)?);
+                edges.push((edge, Some(parse_weight(line_no, weight)?)));
+            }
+            _ => return Err(error(line_no, "expected `u v [w]`")),
+        }
+    }
+
+    Ok(Listing::new(directed, nodes, edges))
+}
+
+pub(crate) fn write<W: Display + Ord>(listing: &Listing<W>) -> String {
+    let mut text = String::new();
+
+    for ((start, end), weight) in &listing.edges {
+        match weight {
+            Some(weight) => writeln!(text, "{} {} {}", start, end, weight),
+            None => writeln!(text, "{} {}", start, end),
+        }
+        .unwrap();
+    }
+
+    for id in listing.isolated() {
+        writeln!(text, "{}", id).unwrap();
+    }
+
+    text
+}
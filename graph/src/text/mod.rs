@@ -0,0 +1,156 @@
+use crate::{Edge, EdgeID, GraphError, NodeID};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+mod dimacs;
+mod dot;
+mod edge_list;
+
+#[cfg(test)]
+mod tests;
+
+// 图与文本格式之间的中间表示；无权图的边权为 None
+pub(crate) struct Listing<W = i32> {
+    pub(crate) directed: bool,
+    pub(crate) nodes: Vec<NodeID>,
+    pub(crate) edges: Vec<(Edge, Option<W>)>,
+    // 与 edges 一一对应的边编号，读入的文本与无权图没有编号，为空
+    pub(crate) ids: Vec<EdgeID>,
+}
+
+impl<W: Ord> Listing<W> {
+    // 节点与边排序后输出，结果与哈希表的遍历顺序无关
+    pub(crate) fn new(
+        directed: bool,
+        mut nodes: Vec<NodeID>,
+        mut edges: Vec<(Edge, Option<W>)>,
+    ) -> Self {
+        nodes.sort_unstable();
+        edges.sort_unstable();
+
+        Self {
+            directed,
+            nodes,
+            edges,
+            ids: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_ids(
+        directed: bool,
+        nodes: Vec<NodeID>,
+        mut edges: Vec<((Edge, Option<W>), EdgeID)>,
+    ) -> Self {
+        edges.sort_unstable();
+
+        let mut listing = Self::new(directed, nodes, Vec::new());
+        (listing.edges, listing.ids) = edges.into_iter().unzip();
+
+        listing
+    }
+
+    // 不与任何边关联的节点
+    fn isolated(&self) -> impl Iterator<Item = NodeID> + '_ {
+        let linked: HashSet<_> = self
+            .edges
+            .iter()
+            .flat_map(|&((start, end), _)| [start, end])
+            .collect();
+
+        self.nodes
+            .iter()
+            .copied()
+            .filter(move |id| !linked.contains(id))
+    }
+}
+
+// 无权图读入的边权：任何文本都能解析，随即丢弃
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Unweighted;
+
+impl FromStr for Unweighted {
+    type Err = Infallible;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Unweighted)
+    }
+}
+
+// 图类型只需给出到 Listing 的转换，各格式的写出由提供的方法完成
+pub(crate) trait ToListing {
+    type Weight: Display + Ord;
+
+    fn listing(&self) -> Listing<Self::Weight>;
+
+    // highlight 中的边按端点标红，无向图不区分边的方向
+    fn write_dot(&self, highlight: impl IntoIterator<Item = Edge>) -> String {
+        let listing = self.listing();
+        let normalize = |(start, end): Edge| {
+            if listing.directed {
+                (start, end)
+            } else {
+                (start.min(end), start.max(end))
+            }
+        };
+        let highlight: HashSet<_> = highlight.into_iter().map(normalize).collect();
+
+        dot::write(&listing, |i| {
+            highlight.contains(&normalize(listing.edges[i].0))
+        })
+    }
+
+    // highlight 中的边按编号标红，平行边互不影响
+    fn write_dot_by_id(&self, highlight: impl IntoIterator<Item = EdgeID>) -> String {
+        let listing = self.listing();
+        let highlight: HashSet<_> = highlight.into_iter().collect();
+
+        dot::write(&listing, |i| highlight.contains(&listing.ids[i]))
+    }
+
+    fn write_edge_list(&self) -> String {
+        edge_list::write(&self.listing())
+    }
+
+    fn write_dimacs(&self) -> String {
+        dimacs::write(&self.listing())
+    }
+}
+
+// 图类型只需给出从 Listing 的构建，各格式的读入由提供的方法完成
+pub(crate) trait FromListing: Sized {
+    type Weight: FromStr + Ord + Clone;
+
+    const DIRECTED: bool;
+
+    fn from_listing(listing: Listing<Self::Weight>) -> Self;
+
+    fn read_dot(text: &str) -> Result<Self, GraphError> {
+        dot::read(text, Self::DIRECTED).map(Self::from_listing)
+    }
+
+    fn read_edge_list(text: &str) -> Result<Self, GraphError> {
+        edge_list::read(text, Self::DIRECTED).map(Self::from_listing)
+    }
+
+    fn read_dimacs(text: &str) -> Result<Self, GraphError> {
+        dimacs::read(text, Self::DIRECTED).map(Self::from_listing)
+    }
+}
+
+fn error(line: usize, msg: impl Into<String>) -> GraphError {
+    GraphError::Parse(line, msg.into())
+}
+
+fn parse_id(line: usize, token: &str) -> Result<NodeID, GraphError> {
+    token
+        .parse()
+        .map_err(|_| error(line, format!("invalid node id `{}`", token)))
+}
+
+fn parse_weight<W: FromStr>(line: usize, token: &str) -> Result<W, GraphError> {
+    token
+        .parse()
+        .map_err(|_| error(line, format!("invalid weight `{}`", token)))
+}
@@ -0,0 +1,187 @@
+use crate::{DiGraph, GraphError, UnGraph, WeiDiGraph};
+
+#[test]
+fn edge_list() {
+    let text = "# 注释\n0 1 3\n1 2 -4 # 负权\n\n2 0\n5\n";
+    let graph: UnGraph = UnGraph::from_edge_list(text).unwrap();
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edges().len(), 3);
+    assert_eq!(graph.to_edge_list(), "0 1 3\n1 2 -4\n2 0 1\n5\n");
+
+    let digraph: DiGraph = DiGraph::from_edge_list(text).unwrap();
+
+    assert_eq!(digraph.edge_count(), 3);
+    assert_eq!(digraph.to_edge_list(), "0 1\n1 2\n2 0\n5\n");
+
+    // 无权图不解析边权
+    let digraph: DiGraph = DiGraph::from_edge_list("0 1 2.5\n1 2 x\n").unwrap();
+    assert_eq!(digraph.to_edge_list(), "0 1\n1 2\n");
+
+    assert_eq!(
+        UnGraph::<()>::from_edge_list("0 1\n1 x\n").err(),
+        Some(GraphError::Parse(2, "invalid node id `x`".to_string()))
+    );
+    assert_eq!(
+        DiGraph::<()>::from_edge_list("0 1 2 3").err(),
+        Some(GraphError::Parse(1, "expected `u v [w]`".to_string()))
+    );
+}
+
+#[test]
+fn dimacs() {
+    let text = "c 示例\np sp 3 3\na 1 2 5\na 2 3 -1\na 3 1 2\n";
+    let graph: WeiDiGraph = WeiDiGraph::from_dimacs(text).unwrap();
+
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.bellman_ford(0).unwrap().dist_to(2), Some(4));
+    assert_eq!(graph.to_dimacs(), "p sp 3 3\na 1 2 5\na 2 3 -1\na 3 1 2\n");
+
+    let parse_error = |text| WeiDiGraph::<()>::from_dimacs(text).err();
+
+    assert_eq!(
+        parse_error("a 1 2 3\n"),
+        Some(GraphError::Parse(1, "arc before problem line".to_string()))
+    );
+    assert_eq!(
+        parse_error("p sp 2 1\na 1 3 1\n"),
+        Some(GraphError::Parse(
+            2,
+            "node 3 out of range 1..=2".to_string()
+        ))
+    );
+    assert_eq!(
+        parse_error("p sp 2 2\nc\na 1 2 1\n"),
+        Some(GraphError::Parse(3, "expected 2 arcs, found 1".to_string()))
+    );
+}
+
+#[test]
+fn dot() {
+    let text = r#"
+        strict graph G {
+            node [shape=circle];
+            rankdir = LR
+            /* 三角形
+               加一个孤立点 */
+            0 -- 1 -- 2 [label=2];
+            2 -- 0 [weight="7"]
+            3;
+        }
+    "#;
+    let graph: UnGraph = UnGraph::from_dot(text).unwrap();
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    0;\n    1;\n    2;\n    3;\n    \
+         0 -- 1 [label=2];\n    1 -- 2 [label=2];\n    2 -- 0 [label=7];\n}\n"
+    );
+
+    // 往返后结构不变
+    let again: UnGraph = UnGraph::from_dot(&graph.to_dot()).unwrap();
+    assert_eq!(again.to_edge_list(), graph.to_edge_list());
+
+    // 节点的 label 不是边权
+    let labelled: UnGraph =
+        UnGraph::from_dot(r#"graph { 0 [label="A"]; 0 -- 1 [weight=3]; }"#).unwrap();
+    assert_eq!(labelled.to_edge_list(), "0 1 3\n");
+
+    let digraph: DiGraph = DiGraph::from_dot("digraph { 0 -> 1 -> 2; 2 -> 0 }").unwrap();
+    assert_eq!(digraph.edge_count(), 3);
+
+    let parse_error = |text| UnGraph::<()>::from_dot(text).err();
+
+    assert_eq!(
+        parse_error("graph {\n0 -- 1\n1 -> 2\n}"),
+        Some(GraphError::Parse(3, "`->` in graph".to_string()))
+    );
+    assert_eq!(
+        parse_error("digraph { 0 }"),
+        Some(GraphError::Parse(1, "expected `graph`".to_string()))
+    );
+    assert_eq!(
+        parse_error("graph {\n0 -- a\n}"),
+        Some(GraphError::Parse(2, "invalid node id `a`".to_string()))
+    );
+    assert_eq!(
+        parse_error("graph {\n0 -- 1\n"),
+        Some(GraphError::Parse(2, "expected `}`".to_string()))
+    );
+}
+
+#[test]
+fn dot_highlighted() {
+    let mut graph = UnGraph::new();
+
+    graph.add_edge(1, (0, 1), (), ());
+    graph.add_edge(5, (1, 2), (), ());
+    graph.add_edge(2, (0, 2), (), ());
+
+    let mst = graph.prim_mst(0).unwrap();
+    let dot = graph.to_dot_highlighted(mst.iter().map(|edge| edge.id));
+
+    assert!(dot.contains("0 -- 1 [label=1, color=red, penwidth=2];"));
+    assert!(dot.contains("0 -- 2 [label=2, color=red, penwidth=2];"));
+    assert!(dot.contains("1 -- 2 [label=5];"));
+    assert!(dot.contains("0 [color=red];"));
+
+    // 按编号标记，与树边平行的边不标红
+    graph.add_edge(1, (1, 0), (), ());
+    graph.add_edge(4, (2, 0), (), ());
+
+    let mst = graph.kruskal_mst().unwrap();
+    let dot = graph.to_dot_highlighted(mst.iter().map(|edge| edge.id));
+
+    assert_eq!(dot.matches("color=red, penwidth=2").count(), 2);
+    assert!(dot.contains("0 -- 2 [label=2, color=red, penwidth=2];"));
+    assert!(dot.contains("2 -- 0 [label=4];"));
+    assert!(dot.contains("0 -- 1 [label=1, color=red, penwidth=2];"));
+    assert!(dot.contains("1 -- 0 [label=1];"));
+
+    let mut wei_digraph = WeiDiGraph::new();
+    wei_digraph.add_edge(3, (0, 1), (), ());
+    wei_digraph.add_edge(3, (0, 1), (), ());
+
+    let dot = wei_digraph.to_dot_highlighted([1]);
+    assert_eq!(
+        dot,
+        "digraph {\n    0 [color=red];\n    1 [color=red];\n    \
+         0 -> 1 [label=3];\n    0 -> 1 [label=3, color=red, penwidth=2];\n}\n"
+    );
+
+    // 有向图按路径上相邻节点标记
+    let digraph: DiGraph = DiGraph::from_edge_list("0 1\n1 2\n2 0\n").unwrap();
+    let path = digraph.shortest_path(0, 2).unwrap();
+    let path: Vec<_> = path.into_iter().collect();
+    let dot = digraph.to_dot_highlighted(path.windows(2).map(|pair| (pair[0], pair[1])));
+
+    assert!(dot.contains("1 -> 2 [color=red, penwidth=2];"));
+    assert!(dot.contains("2 -> 0;"));
+}
+
+#[test]
+fn dimacs_sparse_ids() {
+    let mut graph = UnGraph::new();
+    graph.add_edge(4, (5, 900), (), ());
+    graph.add_edge(2, (900, u32::MAX), (), ());
+
+    // 节点按编号升序重新编为 1..=n，原编号记在注释中
+    let text = graph.to_dimacs();
+    assert_eq!(text, "c ids 5 900 4294967295\np sp 3 2\na 1 2 4\na 2 3 2\n");
+
+    let again: UnGraph = UnGraph::from_dimacs(&text).unwrap();
+
+    assert_eq!(again.node_count(), 3);
+    assert_eq!(again.to_edge_list(), graph.to_edge_list());
+    assert_eq!(again.to_dimacs(), text);
+
+    // 没有编号注释时对应节点 0..n
+    let plain: UnGraph = UnGraph::from_dimacs("p sp 3 2\na 1 2 4\na 2 3 2\n").unwrap();
+    assert_eq!(plain.to_edge_list(), "0 1 4\n1 2 2\n");
+
+    assert_eq!(
+        UnGraph::<()>::from_dimacs("c ids 5 900\np sp 3 0\n").err(),
+        Some(GraphError::Parse(1, "expected 3 ids, found 2".to_string()))
+    );
+}
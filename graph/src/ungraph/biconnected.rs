@@ -0,0 +1,137 @@
+use crate::{NodeID, UnGraph, WeiEdge, Weight};
+use std::collections::{HashMap, HashSet};
+
+// 一次深搜同时得到割点、桥与点双连通分量
+pub(in crate::ungraph) struct LowLink<'a, W> {
+    pub(in crate::ungraph) articulation_points: Vec<NodeID>,
+    pub(in crate::ungraph) bridges: Vec<&'a WeiEdge<W>>,
+    pub(in crate::ungraph) components: Vec<Vec<&'a WeiEdge<W>>>,
+}
+
+// 深搜栈帧：节点，进入该节点的树边，下一条待检查的邻接边
+struct Frame<'a, W> {
+    id: NodeID,
+    parent_edge: Option<&'a WeiEdge<W>>,
+    next: usize,
+}
+
+// 迭代实现的 low-link 深搜，深图也不会栈溢出
+// 自环不影响连通性，直接忽略；平行边按编号区分，不会被误当作树边的回头
+pub(in crate::ungraph) fn search<V, W: Weight>(graph: &UnGraph<V, W>) -> LowLink<'_, W> {
+    let mut ids: Vec<_> = graph.ids().collect();
+    ids.sort_unstable();
+
+    let mut index: HashMap<NodeID, usize> = HashMap::with_capacity(ids.len());
+    let mut low: HashMap<NodeID, usize> = HashMap::with_capacity(ids.len());
+    let mut cut = HashSet::new();
+    let mut bridges = Vec::new();
+    let mut components = Vec::new();
+    let mut edge_stack: Vec<&WeiEdge<W>> = Vec::new();
+
+    for root in ids {
+        if index.contains_key(&root) {
+            continue;
+        }
+
+        index.insert(root, index.len());
+        low.insert(root, index[&root]);
+
+        let mut frames = vec![Frame {
+            id: root,
+            parent_edge: None,
+            next: 0,
+        }];
+        let mut root_children = 0;
+
+        while let Some(frame) = frames.last_mut() {
+            let id = frame.id;
+
+            if let Some(edge) = graph.adj_edges(id).get(frame.next) {
+                frame.next += 1;
+
+                let edge = edge.as_ref();
+                let Some(other) = edge.other(id) else {
+                    continue;
+                };
+
+                if other == id || frame.parent_edge.is_some_and(|parent| parent.id == edge.id) {
+                    continue;
+                }
+
+                match index.get(&other) {
+                    None => {
+                        index.insert(other, index.len());
+                        low.insert(other, index[&other]);
+                        edge_stack.push(edge);
+
+                        if id == root {
+                            root_children += 1;
+                        }
+
+                        frames.push(Frame {
+                            id: other,
+                            parent_edge: Some(edge),
+                            next: 0,
+                        });
+                    }
+                    // 回边只在从后代一侧检查时记录
+                    Some(&other_index) if other_index < index[&id] => {
+                        low.entry(id).and_modify(|low| *low = other_index.min(*low));
+                        edge_stack.push(edge);
+                    }
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            let frame = frames.pop().unwrap();
+            let Some(parent_edge) = frame.parent_edge else {
+                continue;
+            };
+
+            let Some(parent) = parent_edge.other(id) else {
+                continue;
+            };
+            let low_id = low[&id];
+            low.entry(parent).and_modify(|low| *low = low_id.min(*low));
+
+            // 子树无法绕过父节点回到更早的节点
+            if low_id >= index[&parent] {
+                if parent != root {
+                    cut.insert(parent);
+                }
+
+                let mut component = Vec::new();
+
+                while let Some(edge) = edge_stack.pop() {
+                    component.push(edge);
+
+                    if edge.id == parent_edge.id {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+
+            // 子树甚至无法回到父节点本身
+            if low_id > index[&parent] {
+                bridges.push(parent_edge);
+            }
+        }
+
+        if root_children > 1 {
+            cut.insert(root);
+        }
+    }
+
+    let mut articulation_points: Vec<_> = cut.into_iter().collect();
+    articulation_points.sort_unstable();
+
+    LowLink {
+        articulation_points,
+        bridges,
+        components,
+    }
+}
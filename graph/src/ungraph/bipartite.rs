@@ -0,0 +1,200 @@
+use crate::{NodeID, UnGraph, WeiEdge, Weight};
+use std::collections::{HashMap, VecDeque};
+
+// 二着色结果，每个连通分量中编号最小的节点位于 left
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Bipartition {
+    pub left: Vec<NodeID>,
+    pub right: Vec<NodeID>,
+}
+
+impl Bipartition {
+    pub fn is_left(&self, id: NodeID) -> bool {
+        self.left.binary_search(&id).is_ok()
+    }
+
+    pub fn is_right(&self, id: NodeID) -> bool {
+        self.right.binary_search(&id).is_ok()
+    }
+}
+
+// 广搜着色，相邻节点同色时沿搜索树回溯出一个奇环
+pub(in crate::ungraph) fn bipartition<V, W: Weight>(
+    graph: &UnGraph<V, W>,
+) -> Result<Bipartition, Vec<NodeID>> {
+    let mut ids: Vec<_> = graph.ids().collect();
+    ids.sort_unstable();
+
+    let mut colour: HashMap<NodeID, bool> = HashMap::with_capacity(ids.len());
+    let mut edge_to = HashMap::with_capacity(ids.len());
+
+    for &root in &ids {
+        if colour.contains_key(&root) {
+            continue;
+        }
+
+        let mut queue = VecDeque::from([root]);
+        colour.insert(root, false);
+
+        while let Some(id) = queue.pop_front() {
+            for (other, _) in graph.neighbours(id) {
+                match colour.get(&other) {
+                    None => {
+                        colour.insert(other, !colour[&id]);
+                        edge_to.insert(other, id);
+                        queue.push_back(other);
+                    }
+                    Some(&c) if c == colour[&id] => {
+                        return Err(odd_cycle(&edge_to, id, other));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let (left, right) = ids.into_iter().partition(|id| !colour[id]);
+
+    Ok(Bipartition { left, right })
+}
+
+// 同色的两端在广搜树中深度相同，同时上溯至最近公共祖先
+fn odd_cycle(edge_to: &HashMap<NodeID, NodeID>, mut start: NodeID, mut end: NodeID) -> Vec<NodeID> {
+    let mut cycle = Vec::new();
+    let mut back = Vec::new();
+
+    while start != end {
+        cycle.push(start);
+        back.push(end);
+        start = edge_to[&start];
+        end = edge_to[&end];
+    }
+
+    cycle.push(start);
+    cycle.extend(back.into_iter().rev());
+
+    cycle
+}
+
+// Hopcroft-Karp 算法：每轮先从所有未匹配的左侧节点广搜分层，
+// 再沿层次图深搜出一组互不相交的最短增广路径
+pub(in crate::ungraph) fn hopcroft_karp<'a, V, W: Weight>(
+    graph: &'a UnGraph<V, W>,
+    partition: &Bipartition,
+) -> Vec<&'a WeiEdge<W>> {
+    let right_of: HashMap<_, _> = partition
+        .right
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| (id, i))
+        .collect();
+    let adj: Vec<Vec<(usize, &WeiEdge<W>)>> = partition
+        .left
+        .iter()
+        .map(|&id| {
+            graph
+                .neighbours(id)
+                .map(|(other, edge)| (right_of[&other], edge))
+                .collect()
+        })
+        .collect();
+
+    let mut pair_left: Vec<Option<&WeiEdge<W>>> = vec![None; adj.len()];
+    let mut pair_right: Vec<Option<usize>> = vec![None; right_of.len()];
+    let mut level = vec![usize::MAX; adj.len()];
+
+    while layer(&adj, &pair_left, &pair_right, &mut level) {
+        // 每个左侧节点下一条待检查的边
+        let mut next = vec![0; adj.len()];
+
+        for root in 0..adj.len() {
+            if pair_left[root].is_some() {
+                continue;
+            }
+
+            let mut path = Vec::new();
+            let mut u = root;
+
+            loop {
+                let advance = adj[u][next[u]..]
+                    .iter()
+                    .position(|&(v, _)| match pair_right[v] {
+                        None => true,
+                        Some(w) => level[w] == level[u] + 1,
+                    });
+
+                let Some(offset) = advance else {
+                    // 死路：该节点本轮不再参与
+                    level[u] = usize::MAX;
+
+                    let Some(prev) = path.pop() else {
+                        break;
+                    };
+
+                    u = prev;
+                    next[u] += 1;
+                    continue;
+                };
+
+                next[u] += offset;
+                let (v, _) = adj[u][next[u]];
+
+                match pair_right[v] {
+                    Some(w) => {
+                        path.push(u);
+                        u = w;
+                    }
+                    None => {
+                        // 沿路径翻转匹配边与非匹配边
+                        path.push(u);
+
+                        for &u in &path {
+                            let (v, edge) = adj[u][next[u]];
+                            pair_left[u] = Some(edge);
+                            pair_right[v] = Some(u);
+                        }
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    pair_left.into_iter().flatten().collect()
+}
+
+// 以未匹配的左侧节点为第 0 层广搜，存在增广路径时返回 true
+fn layer<W>(
+    adj: &[Vec<(usize, &WeiEdge<W>)>],
+    pair_left: &[Option<&WeiEdge<W>>],
+    pair_right: &[Option<usize>],
+    level: &mut [usize],
+) -> bool {
+    let mut queue = VecDeque::new();
+    let mut found = false;
+
+    for (u, pair) in pair_left.iter().enumerate() {
+        if pair.is_none() {
+            level[u] = 0;
+            queue.push_back(u);
+        } else {
+            level[u] = usize::MAX;
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        for &(v, _) in &adj[u] {
+            match pair_right[v] {
+                None => found = true,
+                Some(w) if level[w] == usize::MAX => {
+                    level[w] = level[u] + 1;
+                    queue.push_back(w);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    found
+}
@@ -0,0 +1,59 @@
+use crate::{Components, NodeID, UnGraph, Weight};
+use std::collections::{HashMap, VecDeque};
+use union_find::UF;
+
+// 两种实现都按节点编号升序开新分量，编号结果一致
+
+// 广搜：从每个未标记的节点出发，搜到的节点同属一个分量
+pub(in crate::ungraph) fn bfs<V, W: Weight>(graph: &UnGraph<V, W>) -> Components {
+    let mut ids: Vec<_> = graph.ids().collect();
+    ids.sort_unstable();
+
+    let mut components = Components::with_capacity(ids.len());
+
+    for root in ids {
+        if components.id(root).is_some() {
+            continue;
+        }
+
+        let component = components.open(root);
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(id) = queue.pop_front() {
+            for (other, _) in graph.neighbours(id) {
+                if components.id(other).is_none() {
+                    components.join(other, component);
+                    queue.push_back(other);
+                }
+            }
+        }
+    }
+
+    components
+}
+
+// 并查集：合并每条边的两端，同根的节点同属一个分量
+pub(in crate::ungraph) fn union_find<V, W: Weight>(graph: &UnGraph<V, W>) -> Components {
+    let mut uf = UF::from_iter(graph.ids());
+
+    for edge in graph.edge_refs() {
+        uf.union(edge.edge.0, edge.edge.1);
+    }
+
+    let mut ids: Vec<_> = graph.ids().collect();
+    ids.sort_unstable();
+
+    let mut components = Components::with_capacity(ids.len());
+    let mut component_of: HashMap<NodeID, usize> = HashMap::new();
+
+    for id in ids {
+        match component_of.get(&uf.find(id)) {
+            Some(&component) => components.join(id, component),
+            None => {
+                component_of.insert(uf.find(id), components.open(id));
+            }
+        }
+    }
+
+    components
+}
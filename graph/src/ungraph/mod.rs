@@ -1,24 +1,46 @@
-use self::mst::{kruskal, lazy_prim, prim};
-use super::{Edge, NodeID, NodeNotInGraph, WeiEdge, Weight};
-use std::collections::{HashMap, HashSet};
-use std::rc::Rc;
+use self::bipartite::{bipartition, hopcroft_karp};
+use self::mst::{boruvka, kruskal, lazy_prim, prim};
+use super::csr::CsrGraph;
+use super::path::{
+    astar, bellman_ford, dijkstra, floyd_warshall, johnson, DistanceMatrix, ShortestPaths,
+};
+use super::text::{FromListing, Listing, ToListing};
+use super::{Adjacency, Components, Edge, EdgeID, GraphError, NodeID, WeiEdge, Weight};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use std::sync::Arc;
 
+#[cfg(feature = "parallel")]
+use super::path::parallel;
+
+mod biconnected;
+mod bipartite;
+mod connectivity;
 mod mst;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
+
+pub use self::bipartite::Bipartition;
+pub use self::mst::SpanningForest;
 
-struct Node<V> {
-    #[allow(dead_code)]
+struct Node<V, W> {
     element: V,
-    adj_edges: Vec<Rc<WeiEdge>>,
+    adj_edges: Vec<Arc<WeiEdge<W>>>,
 }
 
-pub struct UnGraph<V = ()> {
-    adj_table: HashMap<NodeID, Node<V>>,
+pub struct UnGraph<V = (), W = i32> {
+    adj_table: HashMap<NodeID, Node<V, W>>,
+    // 下一条边的编号，删除的边不回收编号
+    next_edge: EdgeID,
+    // 简单图中同一对端点间至多一条边
+    multigraph: bool,
 }
 
-impl<V> Node<V> {
+impl<V, W> Node<V, W> {
     fn new(element: V) -> Self {
         Self {
             element,
@@ -27,51 +49,204 @@ impl<V> Node<V> {
     }
 }
 
-impl<V> UnGraph<V> {
-    fn add_node(&mut self, id: NodeID, elt: V) {
-        self.adj_table.entry(id).or_insert(Node::new(elt));
+impl<V, W: Weight> UnGraph<V, W> {
+    fn adj_edges(&self, id: NodeID) -> &[Arc<WeiEdge<W>>] {
+        self.adj_table.get(&id).unwrap().adj_edges.as_slice()
+    }
+
+    // 邻接边及其另一端点
+    fn neighbours(&self, id: NodeID) -> impl Iterator<Item = (NodeID, &WeiEdge<W>)> + '_ {
+        self.adj_edges(id)
+            .iter()
+            .filter_map(move |edge| Some((edge.other(id)?, edge.as_ref())))
     }
 
-    fn adj_edges(&self, id: NodeID) -> &[Rc<WeiEdge>] {
-        self.adj_table.get(&id).unwrap().adj_edges.as_slice()
+    // 每条边只从端点 edge.0 的邻接表中取出一次
+    fn edge_refs(&self) -> impl Iterator<Item = &WeiEdge<W>> + '_ {
+        self.adj_table.iter().flat_map(|(&id, node)| {
+            node.adj_edges
+                .iter()
+                .filter(move |edge| edge.edge.0 == id)
+                .map(|edge| edge.as_ref())
+        })
+    }
+}
+
+impl<V, W: Weight> Adjacency for UnGraph<V, W> {
+    type Weight = W;
+
+    fn ids(&self) -> impl Iterator<Item = NodeID> + '_ {
+        self.adj_table.keys().copied()
+    }
+
+    fn node_count(&self) -> usize {
+        self.adj_table.len()
+    }
+
+    fn contains_id(&self, id: NodeID) -> bool {
+        self.adj_table.contains_key(&id)
+    }
+
+    fn successors(&self, id: NodeID) -> impl Iterator<Item = (NodeID, W)> + '_ {
+        self.neighbours(id)
+            .map(|(other, edge)| (other, edge.weight))
     }
 }
 
-impl<V> UnGraph<V> {
+impl<V, W: Weight> UnGraph<V, W> {
+    // 允许平行边的多重图
     pub fn new() -> Self {
         Self {
             adj_table: HashMap::new(),
+            next_edge: 0,
+            multigraph: true,
+        }
+    }
+
+    // 不允许平行边的简单图
+    pub fn simple() -> Self {
+        Self {
+            multigraph: false,
+            ..Self::new()
         }
     }
 
-    pub fn add_edge(&mut self, weight: Weight, edge: Edge, start: V, end: V) {
+    pub fn is_multigraph(&self) -> bool {
+        self.multigraph
+    }
+
+    // 节点已存在时不做修改，返回 false
+    pub fn add_node(&mut self, id: NodeID, elt: V) -> bool {
+        if self.contains_id(id) {
+            return false;
+        }
+
+        self.adj_table.insert(id, Node::new(elt));
+        true
+    }
+
+    // 替换节点的值，返回旧值
+    pub fn update_node(&mut self, id: NodeID, elt: V) -> Result<V, GraphError> {
+        self.node_weight_mut(id)
+            .map(|old| mem::replace(old, elt))
+            .ok_or(GraphError::NodeNotInGraph(id))
+    }
+
+    // 返回边的编号；简单图中端点间已有边时，改为更新其权重并沿用原编号
+    pub fn add_edge(&mut self, weight: W, edge: Edge, start: V, end: V) -> EdgeID {
         self.add_node(edge.0, start);
         self.add_node(edge.1, end);
 
-        let wei_edge = Rc::new(WeiEdge::new(weight, edge));
+        let id = match self.find_edge(edge) {
+            Some(old) if !self.multigraph => {
+                self.detach(&old);
+                old.id
+            }
+            _ => {
+                self.next_edge += 1;
+                self.next_edge - 1
+            }
+        };
+        let wei_edge = Arc::new(WeiEdge::new(id, weight, edge));
 
+        // 自环只在邻接表中出现一次
+        let ends = if edge.0 == edge.1 {
+            &[edge.0][..]
+        } else {
+            &[edge.0, edge.1]
+        };
+
+        for &end in ends {
+            self.adj_table
+                .entry(end)
+                .and_modify(|node| node.adj_edges.push(Arc::clone(&wei_edge)));
+        }
+
+        id
+    }
+
+    fn find_edge(&self, edge: Edge) -> Option<Arc<WeiEdge<W>>> {
         self.adj_table
-            .entry(edge.0)
-            .and_modify(|node| node.adj_edges.push(Rc::clone(&wei_edge)));
-        self.adj_table
-            .entry(edge.1)
-            .and_modify(|node| node.adj_edges.push(Rc::clone(&wei_edge)));
+            .get(&edge.0)?
+            .adj_edges
+            .iter()
+            .find(|this| this.other(edge.0) == Some(edge.1))
+            .cloned()
     }
 
-    pub fn edges(&self) -> HashSet<&WeiEdge> {
-        let mut edges = HashSet::new();
+    // 从两个端点的邻接表中删除这条边
+    fn detach(&mut self, wei_edge: &WeiEdge<W>) {
+        for id in [wei_edge.edge.0, wei_edge.edge.1] {
+            self.adj_table
+                .entry(id)
+                .and_modify(|node| node.adj_edges.retain(|this| this.id != wei_edge.id));
+        }
+    }
+
+    // 删除节点，同时从另一端点的邻接表中删除共享的边
+    pub fn remove_node(&mut self, id: NodeID) -> Option<V> {
+        let node = self.adj_table.remove(&id)?;
 
-        for node in self.adj_table.values() {
-            for edge in &node.adj_edges {
-                edges.insert(edge.as_ref());
+        for edge in &node.adj_edges {
+            if let Some(other) = edge
+                .other(id)
+                .and_then(|other| self.adj_table.get_mut(&other))
+            {
+                other.adj_edges.retain(|this| this.id != edge.id);
             }
         }
 
+        Some(node.element)
+    }
+
+    // 删除端点为 edge 的一条边（不分方向），返回其权重
+    pub fn remove_edge(&mut self, edge: Edge) -> Option<W> {
+        let wei_edge = self.find_edge(edge)?;
+        self.detach(&wei_edge);
+
+        Some(wei_edge.weight)
+    }
+
+    pub fn retain_nodes(&mut self, mut f: impl FnMut(NodeID, &V) -> bool) {
+        let removed: HashSet<_> = self
+            .adj_table
+            .iter()
+            .filter(|(&id, node)| !f(id, &node.element))
+            .map(|(&id, _)| id)
+            .collect();
+
+        self.adj_table.retain(|id, _| !removed.contains(id));
+
+        for node in self.adj_table.values_mut() {
+            node.adj_edges
+                .retain(|edge| !removed.contains(&edge.edge.0) && !removed.contains(&edge.edge.1));
+        }
+    }
+
+    pub fn retain_edges(&mut self, mut f: impl FnMut(&WeiEdge<W>) -> bool) {
+        // 每条边在两个端点处各出现一次，只判定一次
+        let mut kept = HashMap::new();
+
+        for node in self.adj_table.values_mut() {
+            node.adj_edges
+                .retain(|edge| *kept.entry(edge.id).or_insert_with(|| f(edge)));
+        }
+    }
+
+    // 按编号升序，平行边各占一项
+    pub fn edges(&self) -> Vec<&WeiEdge<W>> {
+        let mut edges: Vec<_> = self.edge_refs().collect();
+        edges.sort_unstable_by_key(|edge| edge.id);
+
         edges
     }
 
+    pub fn edge_count(&self) -> usize {
+        self.edge_refs().count()
+    }
+
     pub fn ids(&self) -> impl Iterator<Item = NodeID> + '_ {
-        self.adj_table.keys().map(|&id| id)
+        self.adj_table.keys().copied()
     }
 
     pub fn node_count(&self) -> usize {
@@ -82,6 +257,18 @@ impl<V> UnGraph<V> {
         self.adj_table.contains_key(&id)
     }
 
+    pub fn node_weight(&self, id: NodeID) -> Option<&V> {
+        self.adj_table.get(&id).map(|node| &node.element)
+    }
+
+    pub fn node_weight_mut(&mut self, id: NodeID) -> Option<&mut V> {
+        self.adj_table.get_mut(&id).map(|node| &mut node.element)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeID, &V)> + '_ {
+        self.adj_table.iter().map(|(&id, node)| (id, &node.element))
+    }
+
     pub fn len(&self) -> usize {
         self.adj_table.len()
     }
@@ -91,21 +278,310 @@ impl<V> UnGraph<V> {
     }
 }
 
+impl<V, W: Weight> Default for UnGraph<V, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, W: Weight> Index<NodeID> for UnGraph<V, W> {
+    type Output = V;
+
+    fn index(&self, id: NodeID) -> &V {
+        self.node_weight(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+impl<V, W: Weight> IndexMut<NodeID> for UnGraph<V, W> {
+    fn index_mut(&mut self, id: NodeID) -> &mut V {
+        self.node_weight_mut(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+// 连通性方法
+impl<V, W: Weight> UnGraph<V, W> {
+    // 按节点编号升序为各分量编号
+    pub fn connected_components(&self) -> Components {
+        connectivity::bfs(self)
+    }
+
+    // 结果与 connected_components 相同，基于并查集
+    pub fn uf_connected_components(&self) -> Components {
+        connectivity::union_find(self)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.connected_components().count()
+    }
+
+    // 空图视为连通
+    pub fn is_connected(&self) -> bool {
+        self.component_count() <= 1
+    }
+}
+
 // 生成树方法
-impl<V> UnGraph<V> {
-    pub fn lazy_prim_mst(&self, root: NodeID) -> Result<Vec<&'_ WeiEdge>, NodeNotInGraph> {
-        self.contains_id(root)
-            .then(|| lazy_prim::span(self, root))
-            .ok_or(NodeNotInGraph(root))
+impl<V, W: Weight> UnGraph<V, W> {
+    // 生成树须覆盖所有节点，否则图不连通
+    fn spanned<'a>(&self, mst: Vec<&'a WeiEdge<W>>) -> Result<Vec<&'a WeiEdge<W>>, GraphError> {
+        (mst.len() + 1 == self.node_count())
+            .then_some(mst)
+            .ok_or(GraphError::Disconnected)
+    }
+
+    pub fn lazy_prim_mst(&self, root: NodeID) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        if !self.contains_id(root) {
+            return Err(GraphError::NodeNotInGraph(root));
+        }
+
+        self.spanned(lazy_prim::span(self, root))
+    }
+
+    pub fn prim_mst(&self, root: NodeID) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        if !self.contains_id(root) {
+            return Err(GraphError::NodeNotInGraph(root));
+        }
+
+        self.spanned(prim::span(self, root))
+    }
+
+    pub fn kruskal_mst(&self) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        if self.is_empty() {
+            return Err(GraphError::EmptyGraph);
+        }
+
+        self.spanned(kruskal::span(self))
+    }
+
+    pub fn boruvka_mst(&self) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        if self.is_empty() {
+            return Err(GraphError::EmptyGraph);
+        }
+
+        self.spanned(boruvka::span(self))
+    }
+
+    // 各轮在多个线程上并行寻找分量的最小边
+    #[cfg(feature = "parallel")]
+    pub fn par_boruvka_mst(&self) -> Result<Vec<&'_ WeiEdge<W>>, GraphError>
+    where
+        W: Sync,
+    {
+        if self.is_empty() {
+            return Err(GraphError::EmptyGraph);
+        }
+
+        self.spanned(boruvka::par_span(self))
+    }
+
+    pub fn minimum_spanning_forest(&self) -> SpanningForest<'_, W> {
+        SpanningForest::new(self)
+    }
+}
+
+// 最短路径方法
+impl<V, W: Weight> UnGraph<V, W> {
+    pub fn dijkstra(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        dijkstra::search(self, src)
+    }
+
+    // 按层并行展开的广搜，距离为边数
+    #[cfg(feature = "parallel")]
+    pub fn par_bfs(&self, src: NodeID) -> Result<ShortestPaths<usize>, GraphError>
+    where
+        V: Sync,
+        W: Send + Sync,
+    {
+        parallel::bfs(self, src)
+    }
+
+    // 并行求各起点的单源最短路径，结果与 sources 一一对应
+    #[cfg(feature = "parallel")]
+    pub fn par_dijkstra_batch(
+        &self,
+        sources: &[NodeID],
+    ) -> Result<Vec<ShortestPaths<W>>, GraphError>
+    where
+        V: Sync,
+        W: Send + Sync,
+    {
+        parallel::dijkstra_batch(self, sources)
+    }
+
+    // 到达终点即停止
+    pub fn astar(
+        &self,
+        src: NodeID,
+        dest: NodeID,
+        heuristic: impl Fn(NodeID) -> W,
+    ) -> Result<(VecDeque<NodeID>, W), GraphError> {
+        astar::search(self, src, dest, heuristic)
+    }
+
+    pub fn bellman_ford(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        if !self.contains_id(src) {
+            return Err(GraphError::NodeNotInGraph(src));
+        }
+
+        bellman_ford::search(self, src).map_err(GraphError::NegativeCycle)
+    }
+
+    pub fn spfa(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        if !self.contains_id(src) {
+            return Err(GraphError::NodeNotInGraph(src));
+        }
+
+        bellman_ford::spfa(self, src).map_err(GraphError::NegativeCycle)
+    }
+
+    pub fn floyd_warshall(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        floyd_warshall::search(self).map_err(GraphError::NegativeCycle)
+    }
+
+    // 适用于含负权边的稀疏图
+    pub fn johnson(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        johnson::search(self).map_err(GraphError::NegativeCycle)
+    }
+}
+
+// 双连通性方法
+impl<V, W: Weight> UnGraph<V, W> {
+    // 割点，按编号升序排列
+    pub fn articulation_points(&self) -> Vec<NodeID> {
+        biconnected::search(self).articulation_points
+    }
+
+    pub fn bridges(&self) -> Vec<&'_ WeiEdge<W>> {
+        biconnected::search(self).bridges
+    }
+
+    // 点双连通分量，每个分量由其中的边组成
+    pub fn biconnected_components(&self) -> Vec<Vec<&'_ WeiEdge<W>>> {
+        biconnected::search(self).components
+    }
+}
+
+// 二分图方法
+impl<V, W: Weight> UnGraph<V, W> {
+    // 不是二分图时返回一个奇环作为反例
+    pub fn is_bipartite(&self) -> Result<Bipartition, GraphError> {
+        bipartition(self).map_err(GraphError::OddCycle)
+    }
+
+    // 最大匹配，每条匹配边按左侧端点编号排列
+    pub fn hopcroft_karp_matching(&self) -> Result<Vec<&'_ WeiEdge<W>>, GraphError> {
+        let partition = self.is_bipartite()?;
+
+        Ok(hopcroft_karp(self, &partition))
+    }
+}
+
+// 压缩存储方法
+impl<V, W: Weight> UnGraph<V, W> {
+    // 冻结为只读的压缩稀疏行图，边保留原编号
+    pub fn to_csr(&self) -> CsrGraph<W> {
+        let edges = self
+            .edge_refs()
+            .map(|edge| WeiEdge::new(edge.id, edge.weight, edge.edge))
+            .collect();
+
+        CsrGraph::build(false, self.ids(), edges)
+    }
+}
+
+// 文本格式方法
+impl<V, W: Weight + Display> ToListing for UnGraph<V, W> {
+    type Weight = W;
+
+    fn listing(&self) -> Listing<W> {
+        let edges = self
+            .edge_refs()
+            .map(|edge| ((edge.edge, Some(edge.weight)), edge.id))
+            .collect();
+
+        Listing::with_ids(false, self.ids().collect(), edges)
+    }
+}
+
+impl<V: Default, W: Weight + FromStr + From<u8>> FromListing for UnGraph<V, W> {
+    type Weight = W;
+
+    const DIRECTED: bool = false;
+
+    fn from_listing(listing: Listing<W>) -> Self {
+        let mut graph = Self::new();
+
+        for id in listing.nodes {
+            graph.add_node(id, V::default());
+        }
+
+        // 未给出边权时按 1 处理
+        for (edge, weight) in listing.edges {
+            graph.add_edge(
+                weight.unwrap_or_else(|| W::from(1)),
+                edge,
+                V::default(),
+                V::default(),
+            );
+        }
+
+        graph
+    }
+}
+
+impl<V, W: Weight> UnGraph<V, W> {
+    pub fn to_dot(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_dot([])
+    }
+
+    // 按编号将生成树、路径等结果中的边在图中标红
+    pub fn to_dot_highlighted(&self, highlight: impl IntoIterator<Item = EdgeID>) -> String
+    where
+        W: Display,
+    {
+        self.write_dot_by_id(highlight)
+    }
+
+    pub fn from_dot(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_dot(text)
+    }
+
+    pub fn to_edge_list(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_edge_list()
+    }
+
+    pub fn from_edge_list(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_edge_list(text)
     }
 
-    pub fn prim_mst(&self, root: NodeID) -> Result<Vec<&'_ WeiEdge>, NodeNotInGraph> {
-        self.contains_id(root)
-            .then(|| prim::span(self, root))
-            .ok_or(NodeNotInGraph(root))
+    pub fn to_dimacs(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_dimacs()
     }
 
-    pub fn kruskal_mst(&self) -> Option<Vec<&'_ WeiEdge>> {
-        (!self.is_empty()).then(|| kruskal::span(self))
+    pub fn from_dimacs(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_dimacs(text)
     }
 }
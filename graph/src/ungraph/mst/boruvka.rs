@@ -0,0 +1,98 @@
+use crate::{NodeID, UnGraph, WeiEdge, Weight};
+use std::collections::HashMap;
+use union_find::UF;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+type Cheapest<'a, W> = HashMap<NodeID, &'a WeiEdge<W>>;
+
+fn update<'a, W: Weight>(cheapest: &mut Cheapest<'a, W>, root: NodeID, edge: &'a WeiEdge<W>) {
+    cheapest
+        .entry(root)
+        .and_modify(|this| {
+            // 权重相同时按编号区分，保证各分量选边的顺序一致，不会成环
+            if edge.cmp_weight(this).is_lt() {
+                *this = edge;
+            }
+        })
+        .or_insert(edge);
+}
+
+// 找出每个分量连向外部的最小边，键为分量的根
+fn cheapest_edges<'a, W: Weight>(edges: &[&'a WeiEdge<W>], uf: &UF) -> Cheapest<'a, W> {
+    let mut cheapest = HashMap::new();
+
+    for &edge in edges {
+        let (v, w) = edge.endpoints();
+        let root0 = uf.find(v);
+        let root1 = uf.find(w);
+
+        if root0 != root1 {
+            update(&mut cheapest, root0, edge);
+            update(&mut cheapest, root1, edge);
+        }
+    }
+
+    cheapest
+}
+
+// 各线程分段求最小边，再两两合并
+#[cfg(feature = "parallel")]
+fn par_cheapest_edges<'a, W: Weight + Sync>(edges: &[&'a WeiEdge<W>], uf: &UF) -> Cheapest<'a, W> {
+    edges
+        .par_chunks(4096)
+        .map(|chunk| cheapest_edges(chunk, uf))
+        .reduce(HashMap::new, |mut cheapest, other| {
+            for (root, edge) in other {
+                update(&mut cheapest, root, edge);
+            }
+
+            cheapest
+        })
+}
+
+fn grow<'a, V, W: Weight>(
+    graph: &'a UnGraph<V, W>,
+    find_cheapest: impl Fn(&[&'a WeiEdge<W>], &UF) -> Cheapest<'a, W>,
+) -> Vec<&'a WeiEdge<W>> {
+    let mut uf = UF::from_iter(graph.ids());
+    let mut edges: Vec<_> = graph.edge_refs().collect();
+    let mut mst = Vec::with_capacity(graph.node_count().saturating_sub(1));
+
+    // 每轮各分量至少与另一分量合并，分量数至少减半
+    loop {
+        let cheapest = find_cheapest(&edges, &uf);
+
+        if cheapest.is_empty() {
+            break;
+        }
+
+        for edge in cheapest.into_values() {
+            // 两个分量可能选中同一条边
+            let (v, w) = edge.endpoints();
+
+            if !uf.connected(v, w) {
+                uf.union(v, w);
+                mst.push(edge);
+            }
+        }
+
+        // 丢弃已落入同一分量内的边
+        edges.retain(|edge| {
+            let (v, w) = edge.endpoints();
+            !uf.connected(v, w)
+        });
+    }
+
+    mst
+}
+
+pub(in crate::ungraph) fn span<V, W: Weight>(graph: &UnGraph<V, W>) -> Vec<&WeiEdge<W>> {
+    grow(graph, cheapest_edges)
+}
+
+#[cfg(feature = "parallel")]
+pub(in crate::ungraph) fn par_span<V, W: Weight + Sync>(graph: &UnGraph<V, W>) -> Vec<&WeiEdge<W>> {
+    grow(graph, par_cheapest_edges)
+}
@@ -1,28 +1,32 @@
-use crate::{UnGraph, WeiEdge};
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::{UnGraph, WeiEdge, Weight};
 use union_find::UF;
 
-pub(in crate::ungraph) fn span<'a, V>(graph: &'a UnGraph<V>) -> Vec<&'a WeiEdge> {
+// 图不连通时，得到的是最小生成森林
+pub(in crate::ungraph) fn span<V, W: Weight>(graph: &UnGraph<V, W>) -> Vec<&WeiEdge<W>> {
     let mut uf = UF::from_iter(graph.ids());
-    let mut pq = BinaryHeap::new();
-    let branches = graph.node_count() - 1;
+    // 连通图的生成树恰有 V - 1 条边，森林只会更少
+    let branches = graph.node_count().saturating_sub(1);
     let mut mst = Vec::with_capacity(branches);
 
-    // 准备逐条取出权重最小边
-    for edge in graph.edges() {
-        pq.push(Reverse(edge));
-    }
+    // 准备逐条取出权重最小边，平行边各自参与比较
+    let mut edges: Vec<_> = graph.edge_refs().collect();
+    edges.sort_unstable_by(|a, b| a.cmp_weight(b));
 
-    while let Some(Reverse(wei_edge)) = pq.pop() && mst.len() < branches {
-                // 并查集就是在建树
-                // 点不在树中，就是说点与树中的点不连通，
-                // 收入此点也即收入这次循环的边
-                if !uf.connected(wei_edge.edge.0, wei_edge.edge.1) {
-                    uf.union(wei_edge.edge.0, wei_edge.edge.1);
-                    mst.push(wei_edge);
-                }
+    for wei_edge in edges {
+        if mst.len() == branches {
+            break;
         }
 
+        let (v, w) = wei_edge.endpoints();
+
+        // 并查集就是在建树
+        // 点不在树中，就是说点与树中的点不连通，
+        // 收入此点也即收入这次循环的边
+        if !uf.connected(v, w) {
+            uf.union(v, w);
+            mst.push(wei_edge);
+        }
+    }
+
     mst
 }
@@ -1,15 +1,16 @@
-use crate::{NodeID, UnGraph, WeiEdge};
+use super::ByWeight;
+use crate::{NodeID, UnGraph, WeiEdge, Weight};
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashSet};
 
-struct LazyPrimMST<'a, V> {
-    graph: &'a UnGraph<V>,
+struct LazyPrimMST<'a, V, W> {
+    graph: &'a UnGraph<V, W>,
     marked: HashSet<NodeID>,
-    pq: BinaryHeap<Reverse<&'a WeiEdge>>,
+    pq: BinaryHeap<Reverse<ByWeight<'a, W>>>,
 }
 
-impl<'a, V> LazyPrimMST<'a, V> {
-    fn new(graph: &'a UnGraph<V>) -> Self {
+impl<'a, V, W: Weight> LazyPrimMST<'a, V, W> {
+    fn new(graph: &'a UnGraph<V, W>) -> Self {
         Self {
             graph,
             marked: HashSet::with_capacity(graph.node_count()),
@@ -19,29 +20,34 @@ impl<'a, V> LazyPrimMST<'a, V> {
 
     fn visit(&mut self, start: NodeID) {
         // 记录不属于生成树的边
-        for edge in self.graph.adj_edges(start) {
-            if !self.marked.contains(&edge.other(start)) {
-                self.pq.push(Reverse(edge));
+        for (end, edge) in self.graph.neighbours(start) {
+            if !self.marked.contains(&end) {
+                self.pq.push(Reverse(ByWeight(edge)));
             }
         }
     }
 }
 
-pub(in crate::ungraph) fn span<'a, V>(graph: &'a UnGraph<V>, root: NodeID) -> Vec<&'a WeiEdge> {
+pub(in crate::ungraph) fn span<V, W: Weight>(
+    graph: &UnGraph<V, W>,
+    root: NodeID,
+) -> Vec<&WeiEdge<W>> {
     let mut mst = LazyPrimMST::new(graph);
     let mut res = Vec::with_capacity(graph.node_count() - 1);
 
     mst.marked.insert(root);
     mst.visit(root); // 将根节点的所有邻接边加入优先队列
 
-    while let Some(Reverse(wei_edge)) = mst.pq.pop() {
+    while let Some(Reverse(ByWeight(wei_edge))) = mst.pq.pop() {
         // 边优先队列的入队操作由标记点引发，
         // 故每次循环最多只有一个未标记点；
         // 标记最小边新端点，记录它们的邻接边；
-        if mst.marked.insert(wei_edge.edge.0) {
-            mst.visit(wei_edge.edge.0);
-        } else if mst.marked.insert(wei_edge.edge.1) {
-            mst.visit(wei_edge.edge.1);
+        let (v, w) = wei_edge.endpoints();
+
+        if mst.marked.insert(v) {
+            mst.visit(v);
+        } else if mst.marked.insert(w) {
+            mst.visit(w);
         } else {
             // 没有新端点，说明是生成树内边，跳过
             continue;
@@ -1,3 +1,93 @@
+use crate::{NodeID, UnGraph, WeiEdge, Weight};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use union_find::UF;
+
+pub(super) mod boruvka;
 pub(super) mod kruskal;
 pub(super) mod lazy_prim;
 pub(super) mod prim;
+
+// 按 WeiEdge::cmp_weight 排序的边，用作优先队列的元素
+pub(super) struct ByWeight<'a, W>(pub(super) &'a WeiEdge<W>);
+
+impl<W: Weight> PartialEq for ByWeight<'_, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Weight> Eq for ByWeight<'_, W> {}
+
+impl<W: Weight> PartialOrd for ByWeight<'_, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Weight> Ord for ByWeight<'_, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_weight(other.0)
+    }
+}
+
+// 最小生成森林：每个连通分量一棵生成树
+#[derive(Debug)]
+pub struct SpanningForest<'a, W = i32> {
+    trees: Vec<Vec<&'a WeiEdge<W>>>,
+}
+
+impl<'a, W: Weight> SpanningForest<'a, W> {
+    // 按分量中最小的节点编号排列各树，孤立点对应空树
+    pub(super) fn new<V>(graph: &'a UnGraph<V, W>) -> Self {
+        let edges = kruskal::span(graph);
+        let mut uf = UF::from_iter(graph.ids());
+
+        for edge in &edges {
+            let (v, w) = edge.endpoints();
+            uf.union(v, w);
+        }
+
+        let mut ids: Vec<_> = graph.ids().collect();
+        ids.sort_unstable();
+
+        let mut tree_of: HashMap<NodeID, usize> = HashMap::new();
+
+        for id in ids {
+            let next = tree_of.len();
+            tree_of.entry(uf.find(id)).or_insert(next);
+        }
+
+        let mut trees = vec![Vec::new(); tree_of.len()];
+
+        for edge in edges {
+            trees[tree_of[&uf.find(edge.endpoints().0)]].push(edge);
+        }
+
+        Self { trees }
+    }
+
+    pub fn trees(&self) -> &[Vec<&'a WeiEdge<W>>] {
+        &self.trees
+    }
+
+    pub fn into_trees(self) -> Vec<Vec<&'a WeiEdge<W>>> {
+        self.trees
+    }
+
+    pub fn len(&self) -> usize {
+        self.trees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trees.is_empty()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &'a WeiEdge<W>> + '_ {
+        self.trees.iter().flatten().copied()
+    }
+
+    pub fn total_weight(&self) -> W {
+        self.edges().map(|edge| edge.weight).sum()
+    }
+}
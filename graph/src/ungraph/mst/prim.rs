@@ -1,27 +1,26 @@
 use crate::{NodeID, UnGraph, WeiEdge, Weight};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-struct PrimMST<'a, V> {
-    graph: &'a UnGraph<V>,
+struct PrimMST<'a, V, W> {
+    graph: &'a UnGraph<V, W>,
     marked: HashSet<NodeID>,
-    edge_to: HashMap<NodeID, &'a WeiEdge>,
-    pq: BTreeMap<Weight, NodeID>,
+    edge_to: HashMap<NodeID, &'a WeiEdge<W>>,
+    // 以（权重，节点）为键，权重相同的节点不会互相覆盖
+    pq: BTreeSet<(W, NodeID)>,
 }
 
-impl<'a, V> PrimMST<'a, V> {
-    fn new(graph: &'a UnGraph<V>) -> Self {
+impl<'a, V, W: Weight> PrimMST<'a, V, W> {
+    fn new(graph: &'a UnGraph<V, W>) -> Self {
         Self {
             graph,
             marked: HashSet::with_capacity(graph.node_count()),
             edge_to: HashMap::with_capacity(graph.node_count() - 1),
-            pq: BTreeMap::new(),
+            pq: BTreeSet::new(),
         }
     }
 
     fn visit(&mut self, start: NodeID) {
-        for edge in self.graph.adj_edges(start) {
-            let end = edge.other(start);
-
+        for (end, edge) in self.graph.neighbours(start) {
             // 跳过生成树内边
             if self.marked.contains(&end) {
                 continue;
@@ -32,15 +31,16 @@ impl<'a, V> PrimMST<'a, V> {
                 .entry(end)
                 .and_modify(|this| {
                     if this.weight > edge.weight {
+                        self.pq.remove(&(this.weight, end));
                         *this = edge;
 
                         // 记录到终点的权重
                         // 每个节点都关联唯一最小权重，失效边不会留存
-                        self.pq.insert(edge.weight, end);
+                        self.pq.insert((edge.weight, end));
                     }
                 })
                 .or_insert_with(|| {
-                    self.pq.insert(edge.weight, end);
+                    self.pq.insert((edge.weight, end));
 
                     edge
                 });
@@ -48,11 +48,14 @@ impl<'a, V> PrimMST<'a, V> {
     }
 }
 
-pub(in crate::ungraph) fn span<'a, V>(graph: &'a UnGraph<V>, root: NodeID) -> Vec<&'a WeiEdge> {
+pub(in crate::ungraph) fn span<V, W: Weight>(
+    graph: &UnGraph<V, W>,
+    root: NodeID,
+) -> Vec<&WeiEdge<W>> {
     let mut mst = PrimMST::new(graph);
 
     // 使用0权重启动节点优先队列
-    mst.pq.insert(0, root);
+    mst.pq.insert((W::zero(), root));
 
     // 不断访问最近节点
     while let Some((_, closest)) = mst.pq.pop_first() {
@@ -1,7 +1,9 @@
-use crate::{UnGraph, WeiEdge, Weight};
+use crate::{GraphError, Total, UnGraph, WeiEdge};
+use std::collections::VecDeque;
 use std::io::{self, Write};
 
-fn sample() -> UnGraph {
+// 也供其他模块的测试使用
+pub(crate) fn sample() -> UnGraph {
     let mut graph = UnGraph::new();
 
     graph.add_edge(35, (4, 5), (), ());
@@ -29,7 +31,7 @@ fn print_mst(name: &'static str, mst: Vec<&WeiEdge>) -> io::Result<()> {
     let stdout = io::stdout();
     let mut handle = stdout.lock();
 
-    handle.write(name.as_bytes())?;
+    handle.write_all(name.as_bytes())?;
     for edge in mst {
         handle.write_fmt(format_args!("{:?}\n", edge))?;
     }
@@ -38,8 +40,8 @@ fn print_mst(name: &'static str, mst: Vec<&WeiEdge>) -> io::Result<()> {
     Ok(())
 }
 
-fn weight_sum(mst: &Vec<&WeiEdge>) -> Weight {
-    mst.into_iter().map(|&edge| edge.weight).sum()
+fn weight_sum(mst: &[&WeiEdge]) -> i32 {
+    mst.iter().map(|&edge| edge.weight).sum()
 }
 
 #[test]
@@ -65,3 +67,563 @@ fn kruskal_mst() {
     let mst = graph.kruskal_mst().unwrap();
     print_mst("kruskal_mst\n", mst);
 }
+
+#[test]
+fn dijkstra() {
+    let graph = sample();
+    let paths = graph.dijkstra(0).unwrap();
+
+    assert_eq!(paths.dist_to(3), Some(43));
+    assert_eq!(paths.dist_to(6), Some(58));
+    assert_eq!(paths.path_to(5), Some(VecDeque::from([0, 7, 5])));
+    assert_eq!(paths.path_to(0), Some(VecDeque::from([0])));
+    assert!(graph.dijkstra(8).is_err());
+}
+
+#[test]
+fn node_weight() {
+    let mut graph = UnGraph::new();
+    graph.add_edge(1, (0, 1), 'a', 'b');
+
+    assert_eq!(graph.node_weight(0), Some(&'a'));
+    assert_eq!(graph.node_weight(2), None);
+
+    graph[1] = 'c';
+    assert_eq!(graph[1], 'c');
+    assert_eq!(graph.nodes().count(), 2);
+}
+
+#[test]
+fn remove() {
+    let mut graph = sample();
+
+    assert_eq!(graph.remove_node(6), Some(()));
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edges().len(), 12);
+    assert!(graph.ids().all(|id| graph
+        .adj_edges(id)
+        .iter()
+        .all(|edge| edge.other(id) != Some(6))));
+
+    assert_eq!(graph.remove_edge((7, 0)), Some(16));
+    assert_eq!(graph.remove_edge((7, 0)), None);
+    assert!(graph
+        .adj_edges(0)
+        .iter()
+        .all(|edge| edge.other(0) != Some(7)));
+
+    graph.retain_edges(|edge| edge.weight < 30);
+    assert_eq!(graph.edges().len(), 5);
+    assert_eq!(graph.adj_edges(7).len(), 2);
+
+    graph.retain_nodes(|id, _| id != 7);
+    assert_eq!(graph.edges().len(), 3);
+}
+
+#[test]
+fn add_node() {
+    let mut graph = sample();
+
+    assert!(graph.add_node(8, ()));
+    assert!(!graph.add_node(8, ()));
+    assert_eq!(graph.update_node(8, ()), Ok(()));
+    assert_eq!(graph.update_node(9, ()), Err(GraphError::NodeNotInGraph(9)));
+    assert_eq!(graph.node_count(), 9);
+}
+
+#[test]
+#[allow(unused_must_use)]
+fn boruvka_mst() {
+    let graph = sample();
+    let mst = graph.boruvka_mst().unwrap();
+
+    assert_eq!(weight_sum(&mst), 181);
+    print_mst("boruvka_mst\n", mst);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_boruvka_mst() {
+    let mut graph = UnGraph::new();
+
+    // 网格图，权重由坐标散列得到
+    for i in 0..100u32 {
+        for j in 0..100u32 {
+            let id = i * 100 + j;
+            let weight = ((i * 31 + j * 17) % 23) as i32;
+
+            if i + 1 < 100 {
+                graph.add_edge(weight, (id, id + 100), (), ());
+            }
+            if j + 1 < 100 {
+                graph.add_edge(weight + 1, (id, id + 1), (), ());
+            }
+        }
+    }
+
+    let mst = graph.par_boruvka_mst().unwrap();
+
+    assert_eq!(mst.len(), 9999);
+    assert_eq!(weight_sum(&mst), weight_sum(&graph.prim_mst(0).unwrap()));
+    assert_eq!(weight_sum(&mst), weight_sum(&graph.boruvka_mst().unwrap()));
+}
+
+#[test]
+fn minimum_spanning_forest() {
+    let mut graph = sample();
+    graph.add_node(8, ());
+    graph.add_edge(3, (9, 10), (), ());
+
+    let forest = graph.minimum_spanning_forest();
+
+    assert_eq!(forest.len(), 3);
+    assert_eq!(forest.trees()[0].len(), 7);
+    assert!(forest.trees()[1].is_empty());
+    assert_eq!(forest.total_weight(), 184);
+
+    assert_eq!(graph.kruskal_mst().err(), Some(GraphError::Disconnected));
+    assert_eq!(graph.prim_mst(0).err(), Some(GraphError::Disconnected));
+    assert_eq!(graph.lazy_prim_mst(9).err(), Some(GraphError::Disconnected));
+    assert_eq!(
+        UnGraph::<()>::new().kruskal_mst().err(),
+        Some(GraphError::EmptyGraph)
+    );
+}
+
+#[test]
+fn equal_weights_mst() {
+    let mut graph = UnGraph::new();
+
+    graph.add_edge(1, (0, 1), (), ());
+    graph.add_edge(1, (1, 2), (), ());
+    graph.add_edge(1, (2, 3), (), ());
+    graph.add_edge(1, (3, 0), (), ());
+    graph.add_edge(1, (0, 2), (), ());
+
+    assert_eq!(graph.prim_mst(0).map(|mst| weight_sum(&mst)), Ok(3));
+    assert_eq!(graph.lazy_prim_mst(0).map(|mst| weight_sum(&mst)), Ok(3));
+    assert_eq!(graph.kruskal_mst().map(|mst| weight_sum(&mst)), Ok(3));
+    assert_eq!(graph.boruvka_mst().map(|mst| weight_sum(&mst)), Ok(3));
+}
+
+#[test]
+fn astar() {
+    let mut graph = UnGraph::new();
+
+    // 10 x 10 网格，中间有一堵墙
+    let wall = |row: u32, col: u32| col == 5 && row < 8;
+
+    for row in 0..10 {
+        for col in 0..10 {
+            let id = row * 10 + col;
+
+            if wall(row, col) {
+                continue;
+            }
+            if row + 1 < 10 && !wall(row + 1, col) {
+                graph.add_edge(1, (id, id + 10), (), ());
+            }
+            if col + 1 < 10 && !wall(row, col + 1) {
+                graph.add_edge(1, (id, id + 1), (), ());
+            }
+        }
+    }
+
+    // 到右上角 9 号节点的曼哈顿距离
+    let manhattan = |id: u32| (id / 10 + 9 - id % 10) as i32;
+    let (path, cost) = graph.astar(90, 9, manhattan).unwrap();
+
+    assert_eq!(Some(cost), graph.dijkstra(90).unwrap().dist_to(9));
+    assert_eq!(path.len() as i32, cost + 1);
+    assert_eq!((path.front(), path.back()), (Some(&90), Some(&9)));
+    assert!(graph.astar(90, 100, manhattan).is_err());
+}
+
+#[test]
+fn is_bipartite() {
+    let mut graph = UnGraph::new();
+
+    // 偶环加一条悬挂边
+    graph.add_edge(1, (0, 1), (), ());
+    graph.add_edge(1, (1, 2), (), ());
+    graph.add_edge(1, (2, 3), (), ());
+    graph.add_edge(1, (3, 0), (), ());
+    graph.add_edge(1, (3, 4), (), ());
+    graph.add_node(5, ());
+
+    let partition = graph.is_bipartite().unwrap();
+
+    assert_eq!(partition.left, [0, 2, 4, 5]);
+    assert_eq!(partition.right, [1, 3]);
+    assert!(partition.is_left(4) && partition.is_right(3));
+
+    // 加入边 (4, 0) 后出现奇环 0 - 3 - 4
+    graph.add_edge(1, (4, 0), (), ());
+
+    let Err(GraphError::OddCycle(cycle)) = graph.is_bipartite() else {
+        panic!("expected an odd cycle");
+    };
+
+    assert_eq!(cycle.len() % 2, 1);
+    assert!((0..cycle.len()).all(|i| {
+        let (start, end) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+        graph
+            .adj_edges(start)
+            .iter()
+            .any(|edge| edge.other(start) == Some(end))
+    }));
+
+    graph.add_edge(1, (6, 6), (), ());
+    graph.remove_edge((4, 0));
+    assert_eq!(graph.is_bipartite(), Err(GraphError::OddCycle(vec![6])));
+}
+
+#[test]
+fn hopcroft_karp_matching() {
+    let mut graph = UnGraph::new();
+
+    // 工人 0..4，任务 10..14
+    for (worker, job) in [
+        (0, 10),
+        (0, 11),
+        (1, 10),
+        (2, 11),
+        (2, 12),
+        (3, 12),
+        (3, 13),
+        (4, 13),
+    ] {
+        graph.add_edge(1, (worker, job), (), ());
+    }
+
+    let matching = graph.hopcroft_karp_matching().unwrap();
+    let mut ends: Vec<_> = matching
+        .iter()
+        .flat_map(|edge| [edge.edge.0, edge.edge.1])
+        .collect();
+    ends.sort_unstable();
+    ends.dedup();
+
+    assert_eq!(matching.len(), 4);
+    assert_eq!(ends.len(), 8);
+
+    // 完美匹配
+    graph.add_edge(1, (4, 14), (), ());
+    assert_eq!(graph.hopcroft_karp_matching().map(|m| m.len()), Ok(5));
+
+    graph.add_edge(1, (0, 1), (), ());
+    graph.add_edge(1, (1, 11), (), ());
+    assert!(graph.hopcroft_karp_matching().is_err());
+}
+
+#[test]
+fn biconnectivity() {
+    let mut graph = UnGraph::new();
+
+    // 两个三角形 0-1-2 与 3-4-5 由桥 (2, 3) 相连，5 上挂一条边 (5, 6)
+    graph.add_edge(1, (0, 1), (), ());
+    graph.add_edge(2, (1, 2), (), ());
+    graph.add_edge(3, (2, 0), (), ());
+    graph.add_edge(4, (2, 3), (), ());
+    graph.add_edge(5, (3, 4), (), ());
+    graph.add_edge(6, (4, 5), (), ());
+    graph.add_edge(7, (5, 3), (), ());
+    graph.add_edge(8, (5, 6), (), ());
+    graph.add_edge(9, (7, 7), (), ());
+
+    assert_eq!(graph.articulation_points(), [2, 3, 5]);
+
+    let mut bridges: Vec<_> = graph.bridges().iter().map(|edge| edge.weight).collect();
+    bridges.sort_unstable();
+    assert_eq!(bridges, [4, 8]);
+
+    let mut components: Vec<Vec<_>> = graph
+        .biconnected_components()
+        .iter()
+        .map(|component| {
+            let mut weights: Vec<_> = component.iter().map(|edge| edge.weight).collect();
+            weights.sort_unstable();
+            weights
+        })
+        .collect();
+    components.sort();
+    assert_eq!(components, [vec![1, 2, 3], vec![4], vec![5, 6, 7], vec![8]]);
+
+    // 平行边使 (2, 3) 不再是桥
+    graph.add_edge(10, (3, 2), (), ());
+    let bridges: Vec<_> = graph.bridges().iter().map(|edge| edge.weight).collect();
+    assert_eq!(bridges, [8]);
+}
+
+#[test]
+fn deep_biconnectivity() {
+    let mut graph = UnGraph::new();
+
+    // 长链上的每条边都是桥，递归实现会栈溢出
+    for id in 0..100_000 {
+        graph.add_edge(1, (id, id + 1), (), ());
+    }
+
+    assert_eq!(graph.bridges().len(), 100_000);
+    assert_eq!(graph.articulation_points().len(), 99_999);
+    assert_eq!(graph.biconnected_components().len(), 100_000);
+}
+
+#[test]
+fn connected_components() {
+    let mut graph = sample();
+
+    assert!(graph.is_connected());
+    assert_eq!(graph.component_count(), 1);
+
+    graph.add_node(8, ());
+    graph.add_edge(3, (10, 9), (), ());
+
+    let components = graph.connected_components();
+
+    assert_eq!(components.count(), 3);
+    assert_eq!(components.id(0), Some(0));
+    assert_eq!(components.id(8), Some(1));
+    assert!(components.connected(9, 10));
+    assert!(!components.connected(0, 9));
+    assert_eq!(components.id(11), None);
+    assert_eq!(
+        components.labels(),
+        graph.uf_connected_components().labels()
+    );
+    assert!(!graph.is_connected());
+
+    assert!(UnGraph::<()>::new().is_connected());
+}
+
+#[test]
+fn generic_weights() {
+    let mut latency: UnGraph<(), Total<f64>> = UnGraph::new();
+
+    latency.add_edge(Total(0.5), (0, 1), (), ());
+    latency.add_edge(Total(0.125), (1, 2), (), ());
+    latency.add_edge(Total(0.25), (0, 2), (), ());
+    latency.add_edge(Total(f64::NAN), (2, 3), (), ());
+
+    let mst = latency.kruskal_mst().unwrap();
+
+    // NaN 在全序中最大，但仍是连到 3 的唯一边
+    assert_eq!(mst.len(), 3);
+    assert!(mst.iter().any(|edge| edge.weight == Total(0.125)));
+    assert!(mst.iter().any(|edge| edge.weight == Total(0.25)));
+    assert_eq!(latency.dijkstra(0).unwrap().dist_to(1), Some(Total(0.375)));
+
+    let mut cost: UnGraph<(), u64> = UnGraph::new();
+
+    cost.add_edge(u64::MAX / 4, (0, 1), (), ());
+    cost.add_edge(u64::MAX / 4, (1, 2), (), ());
+    cost.add_edge(u64::MAX / 2, (0, 2), (), ());
+
+    assert_eq!(cost.prim_mst(0).unwrap().len(), 2);
+    assert_eq!(
+        cost.minimum_spanning_forest().total_weight(),
+        u64::MAX / 4 * 2
+    );
+}
+
+#[test]
+fn negative_zero_weight() {
+    let mut graph: UnGraph<(), Total<f64>> = UnGraph::new();
+    graph.add_edge(Total(-0.0), (0, 1), (), ());
+
+    // -0.0 在全序中小于 0.0，但零权环不是负权环
+    let matrix = graph.floyd_warshall().unwrap();
+    assert_eq!(matrix.dist(0, 1).map(|dist| dist.0), Some(0.0));
+    assert!(graph.johnson().is_ok());
+
+    graph.add_edge(Total(-1.0), (1, 2), (), ());
+
+    match graph.floyd_warshall() {
+        Err(GraphError::NegativeCycle(mut cycle)) => {
+            cycle.sort_unstable();
+            assert_eq!(cycle, [1, 2]);
+        }
+        res => panic!("expected a negative cycle, got {:?}", res.map(|_| ())),
+    }
+}
+
+#[test]
+fn negative_weight() {
+    let mut graph = UnGraph::new();
+    graph.add_edge(-1, (0, 1), (), ());
+
+    // 无向负权边本身就是负权环，搜索须报错而不是反复松弛
+    assert_eq!(
+        graph.dijkstra(0).err(),
+        Some(GraphError::NegativeWeight(0, 1))
+    );
+    assert_eq!(
+        graph.astar(0, 1, |_| 0),
+        Err(GraphError::NegativeWeight(0, 1))
+    );
+    assert_eq!(
+        graph.to_csr().dijkstra(1).err(),
+        Some(GraphError::NegativeWeight(1, 0))
+    );
+
+    #[cfg(feature = "parallel")]
+    assert_eq!(
+        graph.par_dijkstra_batch(&[0]).err(),
+        Some(GraphError::NegativeWeight(0, 1))
+    );
+
+    // 起点不可达的负权边不影响结果，-0.0 不算负权
+    graph.add_edge(3, (2, 3), (), ());
+    assert_eq!(graph.dijkstra(2).unwrap().dist_to(3), Some(3));
+
+    let mut graph: UnGraph<(), Total<f64>> = UnGraph::new();
+    graph.add_edge(Total(-0.0), (0, 1), (), ());
+    assert!(graph.dijkstra(0).unwrap().has_path_to(1));
+}
+
+#[test]
+fn parallel_edges() {
+    let mut graph = UnGraph::new();
+
+    let first = graph.add_edge(5, (0, 1), (), ());
+    let second = graph.add_edge(5, (1, 0), (), ());
+    let third = graph.add_edge(2, (0, 1), (), ());
+    graph.add_edge(7, (1, 2), (), ());
+    graph.add_edge(1, (2, 2), (), ());
+
+    assert!(graph.is_multigraph());
+    assert_eq!(graph.edge_count(), 5);
+
+    let ids: Vec<_> = graph.edges().iter().map(|edge| edge.id).collect();
+    assert_eq!(ids, [first, second, third, 3, 4]);
+    assert_ne!(graph.edges()[0], graph.edges()[1]);
+
+    // 平行边中只取最轻的一条，自环不进入生成树
+    let mst = graph.kruskal_mst().unwrap();
+    assert_eq!(
+        mst.iter().map(|edge| edge.id).collect::<Vec<_>>(),
+        [third, 3]
+    );
+    assert_eq!(graph.lazy_prim_mst(0).unwrap().len(), 2);
+    assert_eq!(weight_sum(&graph.boruvka_mst().unwrap()), 9);
+
+    assert_eq!(graph.remove_edge((1, 0)), Some(5));
+    assert_eq!(graph.edge_count(), 4);
+    assert_eq!(graph.remove_node(2), Some(()));
+    assert_eq!(graph.edge_count(), 2);
+}
+
+#[test]
+fn simple_graph() {
+    let mut graph = UnGraph::simple();
+
+    let id = graph.add_edge(5, (0, 1), (), ());
+    graph.add_edge(3, (1, 2), (), ());
+
+    // 重复添加只更新权重
+    assert_eq!(graph.add_edge(2, (1, 0), (), ()), id);
+    assert!(!graph.is_multigraph());
+    assert_eq!(graph.edge_count(), 2);
+    assert_eq!(graph.edges()[0].weight, 2);
+    assert_eq!(graph.adj_edges(0).len(), 1);
+    assert_eq!(graph.kruskal_mst().map(|mst| weight_sum(&mst)), Ok(5));
+}
+
+#[test]
+fn large_ids() {
+    let (big, bigger) = (u32::MAX - 1, u32::MAX);
+    let edge = WeiEdge::new(0, 1, (big, bigger));
+
+    assert_eq!(edge.endpoints(), (big, bigger));
+    assert_eq!(edge.other(big), Some(bigger));
+    assert_eq!(edge.other(bigger), Some(big));
+    assert_eq!(edge.other(0), None);
+    assert!(edge.is_incident(bigger));
+    assert!(!edge.is_incident(0));
+    assert_eq!(WeiEdge::new(1, 1, (big, big)).other(big), Some(big));
+
+    let mut graph = UnGraph::new();
+    graph.add_edge(3, (big, bigger), (), ());
+    graph.add_edge(1, (bigger, 0), (), ());
+    graph.add_edge(2, (0, big), (), ());
+
+    for mst in [
+        graph.prim_mst(big).unwrap(),
+        graph.lazy_prim_mst(bigger).unwrap(),
+        graph.kruskal_mst().unwrap(),
+        graph.boruvka_mst().unwrap(),
+    ] {
+        assert_eq!(weight_sum(&mst), 3);
+    }
+
+    assert_eq!(graph.dijkstra(big).unwrap().dist_to(bigger), Some(3));
+    assert_eq!(graph.articulation_points(), []);
+    assert!(graph.is_connected());
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<UnGraph<String, Total<f64>>>();
+
+    let graph = &sample();
+
+    // 在一个线程上建图，在多个线程上查询
+    let dists: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|src| scope.spawn(move || graph.dijkstra(src).unwrap().dist_to(6)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(dists, [Some(58), Some(76), Some(40), Some(52)]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_shortest_paths() {
+    let mut graph = UnGraph::new();
+
+    // 网格图，权重由坐标散列得到
+    for i in 0..60u32 {
+        for j in 0..60u32 {
+            let id = i * 60 + j;
+
+            if i + 1 < 60 {
+                graph.add_edge((id * 7919 % 97) as i32, (id, id + 60), (), ());
+            }
+            if j + 1 < 60 {
+                graph.add_edge((id * 104729 % 89) as i32, (id, id + 1), (), ());
+            }
+        }
+    }
+
+    let hops = graph.par_bfs(0).unwrap();
+
+    assert_eq!(hops.dist_to(3599), Some(118));
+    assert_eq!(hops.path_to(3599).map(|path| path.len()), Some(119));
+    assert!(graph
+        .ids()
+        .all(|id| hops.dist_to(id) == Some((id / 60 + id % 60) as usize)));
+
+    let sources = [0, 1234, 3599, 1234];
+    let batch = graph.par_dijkstra_batch(&sources).unwrap();
+
+    for (paths, &src) in batch.iter().zip(&sources) {
+        assert_eq!(paths.src(), src);
+        assert_eq!(paths.distances(), graph.dijkstra(src).unwrap().distances());
+    }
+
+    assert_eq!(
+        graph.par_dijkstra_batch(&[0, 3600]).err(),
+        Some(GraphError::NodeNotInGraph(3600))
+    );
+    assert_eq!(
+        graph.par_bfs(3600).err(),
+        Some(GraphError::NodeNotInGraph(3600))
+    );
+}
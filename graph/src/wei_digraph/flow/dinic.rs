@@ -0,0 +1,93 @@
+use super::{MaxFlow, Network};
+use crate::{NodeID, WeiDiGraph, Weight};
+use std::collections::VecDeque;
+
+impl<W: Weight> Network<'_, W> {
+    // 广搜构建层次图，汇点不可达时返回 None
+    fn levels(&self) -> Option<Vec<usize>> {
+        let mut level = vec![usize::MAX; self.ids.len()];
+        let mut queue = VecDeque::from([self.src]);
+
+        level[self.src] = 0;
+
+        while let Some(v) = queue.pop_front() {
+            for &arc in &self.adj[v] {
+                let w = self.arcs[arc].to;
+
+                if self.residual(arc) > W::zero() && level[w] == usize::MAX {
+                    level[w] = level[v] + 1;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        (level[self.sink] != usize::MAX).then_some(level)
+    }
+
+    // 在层次图上反复寻找增广路径，直到找不到为止（阻塞流）
+    fn blocking_flow(&mut self, level: &[usize]) {
+        // 每个节点下一条待检查的弧，检查过的弧不再回头
+        let mut next = vec![0; self.ids.len()];
+        let mut path: Vec<usize> = Vec::new();
+        let mut v = self.src;
+
+        loop {
+            if v == self.sink {
+                let bottleneck: W = path.iter().map(|&arc| self.residual(arc)).min().unwrap();
+
+                for &arc in &path {
+                    self.push(arc, bottleneck);
+                }
+
+                // 退回到第一条饱和弧的起点
+                let saturated = path
+                    .iter()
+                    .position(|&arc| self.residual(arc) == W::zero())
+                    .unwrap();
+                v = self.from(path[saturated]);
+                path.truncate(saturated);
+                continue;
+            }
+
+            let advance = self.adj[v][next[v]..].iter().position(|&arc| {
+                self.residual(arc) > W::zero() && level[self.arcs[arc].to] == level[v] + 1
+            });
+
+            match advance {
+                Some(offset) => {
+                    next[v] += offset;
+                    let arc = self.adj[v][next[v]];
+                    path.push(arc);
+                    v = self.arcs[arc].to;
+                }
+                None => {
+                    // 死路：从前一个节点跳过这条弧
+                    next[v] = self.adj[v].len();
+
+                    let Some(arc) = path.pop() else {
+                        break;
+                    };
+
+                    v = self.from(arc);
+                    next[v] += 1;
+                }
+            }
+        }
+    }
+}
+
+pub(in crate::wei_digraph) fn max_flow<V, W: Weight>(
+    graph: &WeiDiGraph<V, W>,
+    src: NodeID,
+    sink: NodeID,
+) -> MaxFlow<'_, W> {
+    let mut network = Network::new(graph, src, sink);
+
+    if src != sink {
+        while let Some(level) = network.levels() {
+            network.blocking_flow(&level);
+        }
+    }
+
+    MaxFlow::new(network)
+}
@@ -0,0 +1,55 @@
+use super::{MaxFlow, Network};
+use crate::{NodeID, WeiDiGraph, Weight};
+use std::collections::VecDeque;
+
+impl<W: Weight> Network<'_, W> {
+    // 广搜残量网络，返回到汇点的最短增广路径上各弧
+    fn augmenting_path(&self) -> Option<Vec<usize>> {
+        let mut arc_to = vec![None; self.ids.len()];
+        let mut queue = VecDeque::from([self.src]);
+
+        while let Some(v) = queue.pop_front() {
+            for &arc in &self.adj[v] {
+                let w = self.arcs[arc].to;
+
+                if self.residual(arc) > W::zero() && w != self.src && arc_to[w].is_none() {
+                    arc_to[w] = Some(arc);
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut v = self.sink;
+
+        // 沿弧回溯至源点
+        while v != self.src {
+            let arc = arc_to[v]?;
+            path.push(arc);
+            v = self.from(arc);
+        }
+
+        Some(path)
+    }
+}
+
+pub(in crate::wei_digraph) fn max_flow<V, W: Weight>(
+    graph: &WeiDiGraph<V, W>,
+    src: NodeID,
+    sink: NodeID,
+) -> MaxFlow<'_, W> {
+    let mut network = Network::new(graph, src, sink);
+
+    if src != sink {
+        while let Some(path) = network.augmenting_path() {
+            // 瓶颈容量即本次增广的流量
+            let bottleneck = path.iter().map(|&arc| network.residual(arc)).min().unwrap();
+
+            for arc in path {
+                network.push(arc, bottleneck);
+            }
+        }
+    }
+
+    MaxFlow::new(network)
+}
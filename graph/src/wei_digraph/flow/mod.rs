@@ -0,0 +1,194 @@
+use super::WeiDiGraph;
+use crate::{NodeID, WeiEdge, Weight};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub(super) mod dinic;
+pub(super) mod edmonds_karp;
+
+// 残量网络中的弧，第 i 条弧的反向弧为第 i ^ 1 条
+// 只记录残量而不记录流量，无符号的容量类型也不会出现负数
+struct FlowArc<'a, W> {
+    to: usize,
+    cap: W,
+    residual: W,
+    // 反向弧没有对应的原边
+    edge: Option<&'a WeiEdge<W>>,
+}
+
+struct Network<'a, W> {
+    ids: Vec<NodeID>,
+    adj: Vec<Vec<usize>>,
+    arcs: Vec<FlowArc<'a, W>>,
+    src: usize,
+    sink: usize,
+}
+
+impl<'a, W: Weight> Network<'a, W> {
+    // 节点按编号重排为连续下标；负容量按 0 处理
+    fn new<V>(graph: &'a WeiDiGraph<V, W>, src: NodeID, sink: NodeID) -> Self {
+        let mut ids: Vec<_> = graph.ids().collect();
+        ids.sort_unstable();
+
+        let index_of: HashMap<_, _> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut adj = vec![Vec::new(); ids.len()];
+        let mut arcs = Vec::with_capacity(graph.edge_count() * 2);
+
+        for edge in graph.edges() {
+            let start = index_of[&edge.edge.0];
+            let end = index_of[&edge.edge.1];
+
+            adj[start].push(arcs.len());
+            let cap = edge.weight.max(W::zero());
+
+            arcs.push(FlowArc {
+                to: end,
+                cap,
+                residual: cap,
+                edge: Some(edge),
+            });
+
+            adj[end].push(arcs.len());
+            arcs.push(FlowArc {
+                to: start,
+                cap: W::zero(),
+                residual: W::zero(),
+                edge: None,
+            });
+        }
+
+        Self {
+            src: index_of[&src],
+            sink: index_of[&sink],
+            ids,
+            adj,
+            arcs,
+        }
+    }
+
+    fn from(&self, arc: usize) -> usize {
+        self.arcs[arc ^ 1].to
+    }
+
+    fn residual(&self, arc: usize) -> W {
+        self.arcs[arc].residual
+    }
+
+    // 原边上的流量；反向弧的残量即其原边的流量
+    fn flow(&self, arc: usize) -> W {
+        self.arcs[arc].cap - self.arcs[arc].residual
+    }
+
+    fn push(&mut self, arc: usize, amount: W) {
+        self.arcs[arc].residual = self.arcs[arc].residual - amount;
+        self.arcs[arc ^ 1].residual = self.arcs[arc ^ 1].residual + amount;
+    }
+
+    // 残量网络中从源点可达的节点
+    fn reachable(&self) -> Vec<bool> {
+        let mut marked = vec![false; self.ids.len()];
+        let mut queue = VecDeque::from([self.src]);
+
+        marked[self.src] = true;
+
+        while let Some(v) = queue.pop_front() {
+            for &arc in &self.adj[v] {
+                let w = self.arcs[arc].to;
+
+                if self.residual(arc) > W::zero() && !marked[w] {
+                    marked[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        marked
+    }
+}
+
+pub struct MaxFlow<'a, W = i32> {
+    network: Network<'a, W>,
+    value: W,
+}
+
+// 最小割：源点侧与汇点侧的节点，以及从源点侧指向汇点侧的原边
+#[derive(Debug)]
+pub struct MinCut<'a, W = i32> {
+    pub source_side: HashSet<NodeID>,
+    pub sink_side: HashSet<NodeID>,
+    pub edges: Vec<&'a WeiEdge<W>>,
+}
+
+impl<'a, W: Weight> MaxFlow<'a, W> {
+    // 源点的净流出量：流出原边的流量减去流入原边的流量
+    fn new(network: Network<'a, W>) -> Self {
+        let (out_arcs, in_arcs): (Vec<usize>, Vec<usize>) = network.adj[network.src]
+            .iter()
+            .partition(|&&arc| network.arcs[arc].edge.is_some());
+        let outflow: W = out_arcs.into_iter().map(|arc| network.flow(arc)).sum();
+        let inflow: W = in_arcs.into_iter().map(|arc| network.residual(arc)).sum();
+
+        Self {
+            network,
+            value: outflow - inflow,
+        }
+    }
+
+    pub fn value(&self) -> W {
+        self.value
+    }
+
+    // 每条原边上的流量
+    pub fn flows(&self) -> impl Iterator<Item = (&'a WeiEdge<W>, W)> + '_ {
+        let network = &self.network;
+
+        (0..network.arcs.len())
+            .filter_map(move |arc| network.arcs[arc].edge.map(|edge| (edge, network.flow(arc))))
+    }
+
+    // 残量图：每条残量为正的弧作为一条边，权重为残量
+    pub fn residual(&self) -> WeiDiGraph<(), W> {
+        let network = &self.network;
+        let mut residual = WeiDiGraph::new();
+
+        for &id in &network.ids {
+            residual.add_node(id, ());
+        }
+
+        for arc in 0..network.arcs.len() {
+            if network.residual(arc) > W::zero() {
+                let start = network.ids[network.from(arc)];
+                let end = network.ids[network.arcs[arc].to];
+
+                residual.add_edge(network.residual(arc), (start, end), (), ());
+            }
+        }
+
+        residual
+    }
+
+    pub fn min_cut(&self) -> MinCut<'a, W> {
+        let network = &self.network;
+        let marked = network.reachable();
+        let mut source_side = HashSet::new();
+        let mut sink_side = HashSet::new();
+
+        for (i, &id) in network.ids.iter().enumerate() {
+            if marked[i] {
+                source_side.insert(id);
+            } else {
+                sink_side.insert(id);
+            }
+        }
+
+        let edges = (0..network.arcs.len())
+            .filter(|&arc| marked[network.from(arc)] && !marked[network.arcs[arc].to])
+            .filter_map(|arc| network.arcs[arc].edge)
+            .collect();
+
+        MinCut {
+            source_side,
+            sink_side,
+            edges,
+        }
+    }
+}
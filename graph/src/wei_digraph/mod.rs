@@ -0,0 +1,499 @@
+use self::flow::{dinic, edmonds_karp};
+use super::csr::CsrGraph;
+use super::path::{
+    astar, bellman_ford, dijkstra, floyd_warshall, johnson, DistanceMatrix, ShortestPaths,
+};
+use super::text::{FromListing, Listing, ToListing};
+use super::{Adjacency, Edge, EdgeID, GraphError, NodeID, WeiEdge, Weight};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[cfg(feature = "parallel")]
+use super::path::parallel;
+
+mod flow;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::flow::{MaxFlow, MinCut};
+
+struct Node<V, W> {
+    element: V,
+    out_edges: Vec<Arc<WeiEdge<W>>>,
+    in_edges: Vec<Arc<WeiEdge<W>>>,
+}
+
+pub struct WeiDiGraph<V = (), W = i32> {
+    adj_table: HashMap<NodeID, Node<V, W>>,
+    // 下一条边的编号，删除的边不回收编号
+    next_edge: EdgeID,
+    // 简单图中同一起点到同一终点至多一条边
+    multigraph: bool,
+}
+
+impl<V, W> Node<V, W> {
+    fn new(element: V) -> Self {
+        Self {
+            element,
+            out_edges: Vec::new(),
+            in_edges: Vec::new(),
+        }
+    }
+}
+
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    fn node(&self, id: NodeID) -> Result<&Node<V, W>, GraphError> {
+        self.adj_table
+            .get(&id)
+            .ok_or(GraphError::NodeNotInGraph(id))
+    }
+}
+
+impl<V, W: Weight> Adjacency for WeiDiGraph<V, W> {
+    type Weight = W;
+
+    fn ids(&self) -> impl Iterator<Item = NodeID> + '_ {
+        self.adj_table.keys().copied()
+    }
+
+    fn node_count(&self) -> usize {
+        self.adj_table.len()
+    }
+
+    fn contains_id(&self, id: NodeID) -> bool {
+        self.adj_table.contains_key(&id)
+    }
+
+    fn successors(&self, id: NodeID) -> impl Iterator<Item = (NodeID, W)> + '_ {
+        self.adj_table[&id]
+            .out_edges
+            .iter()
+            .map(|edge| (edge.edge.1, edge.weight))
+    }
+}
+
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    // 允许平行边的多重图
+    pub fn new() -> Self {
+        Self {
+            adj_table: HashMap::new(),
+            next_edge: 0,
+            multigraph: true,
+        }
+    }
+
+    // 不允许平行边的简单图
+    pub fn simple() -> Self {
+        Self {
+            multigraph: false,
+            ..Self::new()
+        }
+    }
+
+    pub fn is_multigraph(&self) -> bool {
+        self.multigraph
+    }
+
+    // 节点已存在时不做修改，返回 false
+    pub fn add_node(&mut self, id: NodeID, elt: V) -> bool {
+        if self.contains_id(id) {
+            return false;
+        }
+
+        self.adj_table.insert(id, Node::new(elt));
+        true
+    }
+
+    // 替换节点的值，返回旧值
+    pub fn update_node(&mut self, id: NodeID, elt: V) -> Result<V, GraphError> {
+        self.node_weight_mut(id)
+            .map(|old| mem::replace(old, elt))
+            .ok_or(GraphError::NodeNotInGraph(id))
+    }
+
+    // 边的方向为 edge.0 -> edge.1
+    // 返回边的编号；简单图中已有同向边时，改为更新其权重并沿用原编号
+    pub fn add_edge(&mut self, weight: W, edge: Edge, start: V, end: V) -> EdgeID {
+        self.add_node(edge.0, start);
+        self.add_node(edge.1, end);
+
+        let id = match self.find_edge(edge) {
+            Some(old) if !self.multigraph => {
+                self.detach(&old);
+                old.id
+            }
+            _ => {
+                self.next_edge += 1;
+                self.next_edge - 1
+            }
+        };
+        let wei_edge = Arc::new(WeiEdge::new(id, weight, edge));
+
+        self.adj_table
+            .entry(edge.0)
+            .and_modify(|node| node.out_edges.push(Arc::clone(&wei_edge)));
+        self.adj_table
+            .entry(edge.1)
+            .and_modify(|node| node.in_edges.push(Arc::clone(&wei_edge)));
+
+        id
+    }
+
+    fn find_edge(&self, edge: Edge) -> Option<Arc<WeiEdge<W>>> {
+        self.adj_table
+            .get(&edge.0)?
+            .out_edges
+            .iter()
+            .find(|this| this.edge.1 == edge.1)
+            .cloned()
+    }
+
+    // 从起点的出边表与终点的入边表中删除这条边
+    fn detach(&mut self, wei_edge: &WeiEdge<W>) {
+        self.adj_table
+            .entry(wei_edge.edge.0)
+            .and_modify(|node| node.out_edges.retain(|this| this.id != wei_edge.id));
+        self.adj_table
+            .entry(wei_edge.edge.1)
+            .and_modify(|node| node.in_edges.retain(|this| this.id != wei_edge.id));
+    }
+
+    // 删除节点，同时从邻居的出边表与入边表中删除关联的边
+    pub fn remove_node(&mut self, id: NodeID) -> Option<V> {
+        let node = self.adj_table.remove(&id)?;
+
+        for edge in &node.out_edges {
+            if let Some(end) = self.adj_table.get_mut(&edge.edge.1) {
+                end.in_edges.retain(|this| this.id != edge.id);
+            }
+        }
+
+        for edge in &node.in_edges {
+            if let Some(start) = self.adj_table.get_mut(&edge.edge.0) {
+                start.out_edges.retain(|this| this.id != edge.id);
+            }
+        }
+
+        Some(node.element)
+    }
+
+    // 删除一条边 edge.0 -> edge.1，返回其权重；存在平行边时只删除其中一条
+    pub fn remove_edge(&mut self, edge: Edge) -> Option<W> {
+        let wei_edge = self.find_edge(edge)?;
+        self.detach(&wei_edge);
+
+        Some(wei_edge.weight)
+    }
+
+    pub fn retain_nodes(&mut self, mut f: impl FnMut(NodeID, &V) -> bool) {
+        let removed: HashSet<_> = self
+            .adj_table
+            .iter()
+            .filter(|(&id, node)| !f(id, &node.element))
+            .map(|(&id, _)| id)
+            .collect();
+
+        self.adj_table.retain(|id, _| !removed.contains(id));
+
+        for node in self.adj_table.values_mut() {
+            node.out_edges
+                .retain(|edge| !removed.contains(&edge.edge.1));
+            node.in_edges.retain(|edge| !removed.contains(&edge.edge.0));
+        }
+    }
+
+    pub fn retain_edges(&mut self, mut f: impl FnMut(&WeiEdge<W>) -> bool) {
+        // 每条边在出边表与入边表中各出现一次，只判定一次
+        let mut kept = HashMap::new();
+
+        for node in self.adj_table.values_mut() {
+            for edges in [&mut node.out_edges, &mut node.in_edges] {
+                edges.retain(|edge| *kept.entry(edge.id).or_insert_with(|| f(edge)));
+            }
+        }
+    }
+
+    pub fn out_edges(
+        &self,
+        id: NodeID,
+    ) -> Result<impl Iterator<Item = &WeiEdge<W>> + '_, GraphError> {
+        self.node(id)
+            .map(|node| node.out_edges.iter().map(|edge| edge.as_ref()))
+    }
+
+    pub fn in_edges(
+        &self,
+        id: NodeID,
+    ) -> Result<impl Iterator<Item = &WeiEdge<W>> + '_, GraphError> {
+        self.node(id)
+            .map(|node| node.in_edges.iter().map(|edge| edge.as_ref()))
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &WeiEdge<W>> + '_ {
+        // 每条边只在起点的出边表中出现一次
+        self.adj_table
+            .values()
+            .flat_map(|node| node.out_edges.iter().map(|edge| edge.as_ref()))
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = NodeID> + '_ {
+        self.adj_table.keys().copied()
+    }
+
+    pub fn contains_id(&self, id: NodeID) -> bool {
+        self.adj_table.contains_key(&id)
+    }
+
+    pub fn node_weight(&self, id: NodeID) -> Option<&V> {
+        self.adj_table.get(&id).map(|node| &node.element)
+    }
+
+    pub fn node_weight_mut(&mut self, id: NodeID) -> Option<&mut V> {
+        self.adj_table.get_mut(&id).map(|node| &mut node.element)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeID, &V)> + '_ {
+        self.adj_table.iter().map(|(&id, node)| (id, &node.element))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adj_table.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.adj_table
+            .values()
+            .map(|node| node.out_edges.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adj_table.is_empty()
+    }
+}
+
+impl<V, W: Weight> Default for WeiDiGraph<V, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, W: Weight> Index<NodeID> for WeiDiGraph<V, W> {
+    type Output = V;
+
+    fn index(&self, id: NodeID) -> &V {
+        self.node_weight(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+impl<V, W: Weight> IndexMut<NodeID> for WeiDiGraph<V, W> {
+    fn index_mut(&mut self, id: NodeID) -> &mut V {
+        self.node_weight_mut(id)
+            .unwrap_or_else(|| panic!("{}", GraphError::NodeNotInGraph(id)))
+    }
+}
+
+// 最短路径方法
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    pub fn dijkstra(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        dijkstra::search(self, src)
+    }
+
+    // 按层并行展开的广搜，距离为边数
+    #[cfg(feature = "parallel")]
+    pub fn par_bfs(&self, src: NodeID) -> Result<ShortestPaths<usize>, GraphError>
+    where
+        V: Sync,
+        W: Send + Sync,
+    {
+        parallel::bfs(self, src)
+    }
+
+    // 并行求各起点的单源最短路径，结果与 sources 一一对应
+    #[cfg(feature = "parallel")]
+    pub fn par_dijkstra_batch(
+        &self,
+        sources: &[NodeID],
+    ) -> Result<Vec<ShortestPaths<W>>, GraphError>
+    where
+        V: Sync,
+        W: Send + Sync,
+    {
+        parallel::dijkstra_batch(self, sources)
+    }
+
+    // 到达终点即停止
+    pub fn astar(
+        &self,
+        src: NodeID,
+        dest: NodeID,
+        heuristic: impl Fn(NodeID) -> W,
+    ) -> Result<(VecDeque<NodeID>, W), GraphError> {
+        astar::search(self, src, dest, heuristic)
+    }
+
+    pub fn bellman_ford(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        if !self.contains_id(src) {
+            return Err(GraphError::NodeNotInGraph(src));
+        }
+
+        bellman_ford::search(self, src).map_err(GraphError::NegativeCycle)
+    }
+
+    pub fn spfa(&self, src: NodeID) -> Result<ShortestPaths<W>, GraphError> {
+        if !self.contains_id(src) {
+            return Err(GraphError::NodeNotInGraph(src));
+        }
+
+        bellman_ford::spfa(self, src).map_err(GraphError::NegativeCycle)
+    }
+
+    pub fn floyd_warshall(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        floyd_warshall::search(self).map_err(GraphError::NegativeCycle)
+    }
+
+    // 适用于含负权边的稀疏图
+    pub fn johnson(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        johnson::search(self).map_err(GraphError::NegativeCycle)
+    }
+}
+
+// 最大流方法
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    fn check_ends(&self, src: NodeID, sink: NodeID) -> Result<(), GraphError> {
+        match [src, sink].into_iter().find(|&id| !self.contains_id(id)) {
+            Some(id) => Err(GraphError::NodeNotInGraph(id)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn dinic_max_flow(&self, src: NodeID, sink: NodeID) -> Result<MaxFlow<'_, W>, GraphError> {
+        self.check_ends(src, sink)?;
+
+        Ok(dinic::max_flow(self, src, sink))
+    }
+
+    pub fn edmonds_karp_max_flow(
+        &self,
+        src: NodeID,
+        sink: NodeID,
+    ) -> Result<MaxFlow<'_, W>, GraphError> {
+        self.check_ends(src, sink)?;
+
+        Ok(edmonds_karp::max_flow(self, src, sink))
+    }
+}
+
+// 压缩存储方法
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    // 冻结为只读的压缩稀疏行图，边保留原编号
+    pub fn to_csr(&self) -> CsrGraph<W> {
+        let edges = self
+            .edges()
+            .map(|edge| WeiEdge::new(edge.id, edge.weight, edge.edge))
+            .collect();
+
+        CsrGraph::build(true, self.ids(), edges)
+    }
+}
+
+// 文本格式方法
+impl<V, W: Weight + Display> ToListing for WeiDiGraph<V, W> {
+    type Weight = W;
+
+    fn listing(&self) -> Listing<W> {
+        let edges = self
+            .edges()
+            .map(|edge| ((edge.edge, Some(edge.weight)), edge.id))
+            .collect();
+
+        Listing::with_ids(true, self.ids().collect(), edges)
+    }
+}
+
+impl<V: Default, W: Weight + FromStr + From<u8>> FromListing for WeiDiGraph<V, W> {
+    type Weight = W;
+
+    const DIRECTED: bool = true;
+
+    fn from_listing(listing: Listing<W>) -> Self {
+        let mut graph = Self::new();
+
+        for id in listing.nodes {
+            graph.add_node(id, V::default());
+        }
+
+        // 未给出边权时按 1 处理
+        for (edge, weight) in listing.edges {
+            graph.add_edge(
+                weight.unwrap_or_else(|| W::from(1)),
+                edge,
+                V::default(),
+                V::default(),
+            );
+        }
+
+        graph
+    }
+}
+
+impl<V, W: Weight> WeiDiGraph<V, W> {
+    pub fn to_dot(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_dot([])
+    }
+
+    // 按编号将生成树、路径等结果中的边在图中标红
+    pub fn to_dot_highlighted(&self, highlight: impl IntoIterator<Item = EdgeID>) -> String
+    where
+        W: Display,
+    {
+        self.write_dot_by_id(highlight)
+    }
+
+    pub fn from_dot(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_dot(text)
+    }
+
+    pub fn to_edge_list(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_edge_list()
+    }
+
+    pub fn from_edge_list(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_edge_list(text)
+    }
+
+    pub fn to_dimacs(&self) -> String
+    where
+        W: Display,
+    {
+        self.write_dimacs()
+    }
+
+    pub fn from_dimacs(text: &str) -> Result<Self, GraphError>
+    where
+        V: Default,
+        W: FromStr + From<u8>,
+    {
+        Self::read_dimacs(text)
+    }
+}
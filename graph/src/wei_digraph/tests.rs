@@ -0,0 +1,388 @@
+use super::WeiDiGraph;
+use crate::{GraphError, Total};
+use std::collections::{HashSet, VecDeque};
+
+fn sample() -> WeiDiGraph {
+    let mut graph = WeiDiGraph::new();
+
+    graph.add_edge(35, (4, 5), (), ());
+    graph.add_edge(35, (5, 4), (), ());
+    graph.add_edge(37, (4, 7), (), ());
+    graph.add_edge(28, (5, 7), (), ());
+    graph.add_edge(28, (7, 5), (), ());
+    graph.add_edge(32, (5, 1), (), ());
+    graph.add_edge(38, (0, 4), (), ());
+    graph.add_edge(26, (0, 2), (), ());
+    graph.add_edge(39, (7, 3), (), ());
+    graph.add_edge(29, (1, 3), (), ());
+    graph.add_edge(34, (2, 7), (), ());
+    graph.add_edge(40, (6, 2), (), ());
+    graph.add_edge(52, (3, 6), (), ());
+    graph.add_edge(58, (6, 0), (), ());
+    graph.add_edge(93, (6, 4), (), ());
+
+    graph
+}
+
+#[test]
+fn adjacency() {
+    let graph = sample();
+
+    assert_eq!(graph.node_count(), 8);
+    assert_eq!(graph.edge_count(), 15);
+    assert_eq!(graph.edges().count(), 15);
+
+    let mut ends: Vec<_> = graph.out_edges(6).unwrap().map(|e| e.edge.1).collect();
+    ends.sort();
+    assert_eq!(ends, [0, 2, 4]);
+
+    let mut starts: Vec<_> = graph.in_edges(4).unwrap().map(|e| e.edge.0).collect();
+    starts.sort();
+    assert_eq!(starts, [0, 5, 6]);
+
+    assert_eq!(
+        graph.out_edges(8).err(),
+        Some(GraphError::NodeNotInGraph(8))
+    );
+}
+
+#[test]
+fn dijkstra() {
+    let mut graph = sample();
+    let paths = graph.dijkstra(0).unwrap();
+
+    assert_eq!(paths.dist_to(1), Some(105));
+    assert_eq!(paths.dist_to(6), Some(151));
+    assert_eq!(paths.path_to(6), Some(VecDeque::from([0, 2, 7, 3, 6])));
+
+    assert_eq!(
+        graph.astar(0, 6, |_| 0),
+        Ok((VecDeque::from([0, 2, 7, 3, 6]), 151))
+    );
+
+    graph.add_node(8, ());
+    assert_eq!(graph.astar(0, 8, |_| 0), Err(GraphError::NoPath(0, 8)));
+}
+
+#[test]
+fn negative_weight() {
+    let mut graph = WeiDiGraph::new();
+    graph.add_edge(2, (0, 1), (), ());
+    graph.add_edge(-1, (1, 2), (), ());
+    graph.add_edge(-5, (3, 0), (), ());
+
+    assert_eq!(
+        graph.dijkstra(0).err(),
+        Some(GraphError::NegativeWeight(1, 2))
+    );
+    assert_eq!(
+        graph.astar(0, 2, |_| 0),
+        Err(GraphError::NegativeWeight(1, 2))
+    );
+    assert_eq!(
+        graph.to_csr().dijkstra(0).err(),
+        Some(GraphError::NegativeWeight(1, 2))
+    );
+
+    #[cfg(feature = "parallel")]
+    assert_eq!(
+        graph.par_dijkstra_batch(&[2, 0]).err(),
+        Some(GraphError::NegativeWeight(1, 2))
+    );
+
+    // 只检查起点可达的边
+    assert_eq!(graph.dijkstra(2).unwrap().dist_to(2), Some(0));
+    assert_eq!(graph.astar(0, 1, |_| 0), Ok((VecDeque::from([0, 1]), 2)));
+}
+
+fn negative_sample() -> WeiDiGraph {
+    let mut graph = WeiDiGraph::new();
+
+    graph.add_edge(35, (4, 5), (), ());
+    graph.add_edge(35, (5, 4), (), ());
+    graph.add_edge(37, (4, 7), (), ());
+    graph.add_edge(28, (5, 7), (), ());
+    graph.add_edge(28, (7, 5), (), ());
+    graph.add_edge(32, (5, 1), (), ());
+    graph.add_edge(38, (0, 4), (), ());
+    graph.add_edge(26, (0, 2), (), ());
+    graph.add_edge(39, (7, 3), (), ());
+    graph.add_edge(29, (1, 3), (), ());
+    graph.add_edge(34, (2, 7), (), ());
+    graph.add_edge(-120, (6, 2), (), ());
+    graph.add_edge(52, (3, 6), (), ());
+    graph.add_edge(-140, (6, 0), (), ());
+    graph.add_edge(-125, (6, 4), (), ());
+
+    graph
+}
+
+fn cycle_weight(graph: &WeiDiGraph, cycle: &[u32]) -> i32 {
+    cycle
+        .iter()
+        .zip(cycle.iter().cycle().skip(1))
+        .map(|(&start, &end)| {
+            graph
+                .out_edges(start)
+                .unwrap()
+                .filter(|e| e.edge.1 == end)
+                .map(|e| e.weight)
+                .min()
+                .unwrap()
+        })
+        .sum()
+}
+
+#[test]
+fn bellman_ford() {
+    let graph = negative_sample();
+
+    for paths in [graph.bellman_ford(0).unwrap(), graph.spfa(0).unwrap()] {
+        assert_eq!(paths.dist_to(1), Some(93));
+        assert_eq!(paths.dist_to(4), Some(26));
+        assert_eq!(paths.path_to(4), Some(VecDeque::from([0, 2, 7, 3, 6, 4])));
+    }
+
+    assert_eq!(graph.spfa(8).err(), Some(GraphError::NodeNotInGraph(8)));
+}
+
+#[test]
+fn negative_cycle() {
+    let mut graph = sample();
+    graph.add_edge(-66, (5, 4), (), ());
+
+    for res in [graph.bellman_ford(0), graph.spfa(0)] {
+        match res {
+            Err(GraphError::NegativeCycle(cycle)) => assert!(cycle_weight(&graph, &cycle) < 0),
+            _ => panic!("negative cycle not detected"),
+        }
+    }
+
+    for res in [graph.floyd_warshall(), graph.johnson()] {
+        match res {
+            Err(GraphError::NegativeCycle(cycle)) => assert!(cycle_weight(&graph, &cycle) < 0),
+            _ => panic!("negative cycle not detected"),
+        }
+    }
+}
+
+#[test]
+fn dense_negative_cycle() {
+    let mut graph = WeiDiGraph::new();
+
+    // 40 个节点两两相连，只有 0 <-> 1 是负权环；不及时停止会使负距离溢出
+    for start in 0..40 {
+        for end in 0..40 {
+            if start != end {
+                let weight = if start + end == 1 { -1 } else { 1 };
+                graph.add_edge(weight, (start, end), (), ());
+            }
+        }
+    }
+
+    match graph.floyd_warshall() {
+        Err(GraphError::NegativeCycle(mut cycle)) => {
+            assert_eq!(cycle_weight(&graph, &cycle), -2);
+            cycle.sort_unstable();
+            assert_eq!(cycle, [0, 1]);
+        }
+        res => panic!("expected a negative cycle, got {:?}", res.map(|_| ())),
+    }
+}
+
+#[test]
+fn remove() {
+    let mut graph = sample();
+
+    assert_eq!(graph.remove_node(6), Some(()));
+    assert_eq!(graph.edge_count(), 11);
+    assert_eq!(graph.in_edges(2).unwrap().count(), 1);
+
+    assert_eq!(graph.remove_edge((0, 2)), Some(26));
+    assert_eq!(graph.in_edges(2).unwrap().count(), 0);
+
+    graph.retain_edges(|edge| edge.weight > 30);
+    assert_eq!(graph.edge_count(), 7);
+    assert_eq!(graph.in_edges(7).unwrap().count(), 2);
+
+    graph.retain_nodes(|id, _| id != 5);
+    assert_eq!(graph.edge_count(), 4);
+    assert_eq!(graph.in_edges(1).unwrap().count(), 0);
+}
+
+#[test]
+fn all_pairs() {
+    let graph = negative_sample();
+
+    for matrix in [graph.floyd_warshall().unwrap(), graph.johnson().unwrap()] {
+        assert_eq!(matrix.ids(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(matrix.dist(0, 4), Some(26));
+        assert_eq!(matrix.path(0, 4), Some(VecDeque::from([0, 2, 7, 3, 6, 4])));
+
+        for src in graph.ids() {
+            let paths = graph.bellman_ford(src).unwrap();
+
+            for dest in graph.ids() {
+                assert_eq!(matrix.dist(src, dest), paths.dist_to(dest));
+                assert_eq!(matrix.path(src, dest), paths.path_to(dest));
+            }
+        }
+    }
+}
+
+fn flow_sample() -> WeiDiGraph {
+    let mut graph = WeiDiGraph::new();
+
+    graph.add_edge(16, (0, 1), (), ());
+    graph.add_edge(13, (0, 2), (), ());
+    graph.add_edge(10, (1, 2), (), ());
+    graph.add_edge(4, (2, 1), (), ());
+    graph.add_edge(12, (1, 3), (), ());
+    graph.add_edge(9, (3, 2), (), ());
+    graph.add_edge(14, (2, 4), (), ());
+    graph.add_edge(7, (4, 3), (), ());
+    graph.add_edge(20, (3, 5), (), ());
+    graph.add_edge(4, (4, 5), (), ());
+
+    graph
+}
+
+#[test]
+fn max_flow() {
+    let graph = flow_sample();
+
+    for flow in [
+        graph.dinic_max_flow(0, 5).unwrap(),
+        graph.edmonds_karp_max_flow(0, 5).unwrap(),
+    ] {
+        assert_eq!(flow.value(), 23);
+
+        // 容量限制与流量守恒
+        let mut net = [0; 6];
+        for (edge, amount) in flow.flows() {
+            assert!((0..=edge.weight).contains(&amount));
+            net[edge.edge.0 as usize] -= amount;
+            net[edge.edge.1 as usize] += amount;
+        }
+        assert_eq!(net, [-23, 0, 0, 0, 0, 23]);
+
+        let cut = flow.min_cut();
+        let mut cut_edges: Vec<_> = cut.edges.iter().map(|edge| edge.edge).collect();
+        cut_edges.sort();
+
+        assert_eq!(cut.source_side, HashSet::from([0, 1, 2, 4]));
+        assert_eq!(cut.sink_side, HashSet::from([3, 5]));
+        assert_eq!(cut_edges, [(1, 3), (4, 3), (4, 5)]);
+
+        // 残量图中汇点不可达
+        let residual = flow.residual();
+        assert!(!residual.dijkstra(0).unwrap().has_path_to(5));
+    }
+
+    assert_eq!(graph.dinic_max_flow(0, 0).map(|flow| flow.value()), Ok(0));
+    assert!(graph.dinic_max_flow(0, 6).is_err());
+}
+
+#[test]
+fn unsigned_max_flow() {
+    let mut graph: WeiDiGraph<(), u64> = WeiDiGraph::new();
+
+    // 超出 i32 范围的容量
+    graph.add_edge(5_000_000_000, (0, 1), (), ());
+    graph.add_edge(3_000_000_000, (0, 2), (), ());
+    graph.add_edge(4_000_000_000, (1, 3), (), ());
+    graph.add_edge(1_000_000_000, (1, 2), (), ());
+    graph.add_edge(6_000_000_000, (2, 3), (), ());
+    graph.add_edge(2_000_000_000, (3, 0), (), ());
+
+    let dinic = graph.dinic_max_flow(0, 3).unwrap();
+
+    assert_eq!(dinic.value(), 8_000_000_000);
+    assert_eq!(
+        graph.edmonds_karp_max_flow(0, 3).unwrap().value(),
+        8_000_000_000
+    );
+    assert!(dinic.flows().all(|(edge, flow)| flow <= edge.weight));
+    assert_eq!(
+        dinic
+            .min_cut()
+            .edges
+            .iter()
+            .map(|edge| edge.weight)
+            .sum::<u64>(),
+        8_000_000_000
+    );
+}
+
+#[test]
+fn float_weights() {
+    let mut graph: WeiDiGraph<(), Total<f64>> = WeiDiGraph::new();
+
+    graph.add_edge(Total(0.25), (0, 1), (), ());
+    graph.add_edge(Total(0.5), (1, 2), (), ());
+    graph.add_edge(Total(1.0), (0, 2), (), ());
+    graph.add_edge(Total(-0.125), (2, 3), (), ());
+
+    let paths = graph.bellman_ford(0).unwrap();
+
+    assert_eq!(paths.dist_to(3), Some(Total(0.625)));
+    assert_eq!(paths.path_to(3), Some(VecDeque::from([0, 1, 2, 3])));
+    assert_eq!(graph.johnson().unwrap().dist(1, 3), Some(Total(0.375)));
+
+    let parsed: WeiDiGraph<(), Total<f64>> =
+        WeiDiGraph::from_edge_list(&graph.to_edge_list()).unwrap();
+    assert_eq!(
+        parsed.bellman_ford(0).unwrap().dist_to(2),
+        Some(Total(0.75))
+    );
+}
+
+#[test]
+fn parallel_edges() {
+    let mut graph = WeiDiGraph::new();
+
+    let first = graph.add_edge(4, (0, 1), (), ());
+    let second = graph.add_edge(4, (0, 1), (), ());
+    graph.add_edge(3, (1, 0), (), ());
+
+    assert_ne!(first, second);
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(graph.dinic_max_flow(0, 1).unwrap().value(), 8);
+
+    assert_eq!(graph.remove_edge((0, 1)), Some(4));
+    assert_eq!(graph.edge_count(), 2);
+    assert_eq!(graph.in_edges(1).unwrap().count(), 1);
+
+    let mut simple = WeiDiGraph::simple();
+    let id = simple.add_edge(4, (0, 1), (), ());
+
+    assert_eq!(simple.add_edge(6, (0, 1), (), ()), id);
+    assert_ne!(simple.add_edge(6, (1, 0), (), ()), id);
+    assert_eq!(simple.edge_count(), 2);
+    assert_eq!(simple.edmonds_karp_max_flow(0, 1).unwrap().value(), 6);
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<WeiDiGraph<String, u64>>();
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_shortest_paths() {
+    let graph = sample();
+    let hops = graph.par_bfs(0).unwrap();
+
+    assert_eq!(hops.dist_to(6), Some(4));
+    assert_eq!(hops.dist_to(1), Some(3));
+    assert_eq!(hops.path_to(1), Some(VecDeque::from([0, 4, 5, 1])));
+    assert_eq!(hops.path_to(6).map(|path| path.len()), Some(5));
+
+    let ids: Vec<_> = graph.ids().collect();
+    let batch = graph.par_dijkstra_batch(&ids).unwrap();
+
+    for (paths, &src) in batch.iter().zip(&ids) {
+        assert_eq!(paths.distances(), graph.dijkstra(src).unwrap().distances());
+    }
+}
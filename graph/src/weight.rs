@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::num::ParseFloatError;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+// 边权：全序，可加减求和，并有零元
+pub trait Weight:
+    Copy + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> + Sum
+{
+    fn zero() -> Self;
+
+    // 加零把浮点数的 -0.0 规范为 0.0，-0.0 不算负数
+    fn is_negative(self) -> bool {
+        self + Self::zero() < Self::zero()
+    }
+}
+
+macro_rules! int_weight {
+    ($($int:ty)*) => {
+        $(
+            impl Weight for $int {
+                fn zero() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+int_weight!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// 浮点数的全序包装，按 total_cmp 比较，-0.0 < 0.0，NaN 大于所有数
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Total<F>(pub F);
+
+macro_rules! float_weight {
+    ($($float:ty)*) => {
+        $(
+            impl PartialEq for Total<$float> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for Total<$float> {}
+
+            impl PartialOrd for Total<$float> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for Total<$float> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.total_cmp(&other.0)
+                }
+            }
+
+            // 与 total_cmp 一致：按位相等即相等
+            impl Hash for Total<$float> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.0.to_bits().hash(state);
+                }
+            }
+
+            impl Add for Total<$float> {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    Total(self.0 + other.0)
+                }
+            }
+
+            impl Sub for Total<$float> {
+                type Output = Self;
+
+                fn sub(self, other: Self) -> Self {
+                    Total(self.0 - other.0)
+                }
+            }
+
+            impl Sum for Total<$float> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    Total(iter.map(|total| total.0).sum())
+                }
+            }
+
+            impl Weight for Total<$float> {
+                fn zero() -> Self {
+                    Total(0.0)
+                }
+            }
+
+            impl From<u8> for Total<$float> {
+                fn from(n: u8) -> Self {
+                    Total(n.into())
+                }
+            }
+
+            impl FromStr for Total<$float> {
+                type Err = ParseFloatError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map(Total)
+                }
+            }
+
+            impl Display for Total<$float> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+float_weight!(f32 f64);